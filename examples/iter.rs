@@ -18,7 +18,7 @@ impl User {
 
 fn main() {
     // Some example users
-    let users = vec![
+    let users = [
         User::new(0, "Alice".into(), "hunter2".into()),
         User::new(1, "Bob".into(), "swordfish".into()),
         User::new(2, "Charlie".into(), "1337".into()),
@@ -0,0 +1,197 @@
+//! Typed values for the [`Role`](crate::Role) and `aria-*` attributes
+//!
+//! Attributes whose values are `true` or `false` also accept a [`bool`].
+//!
+//! ```
+//! use hotman::*;
+//!
+//! let menu_button = button((
+//!     Role(aria::Role::Button),
+//!     AriaHaspopup(aria::HasPopup::Menu),
+//!     AriaExpanded(false),
+//!     "Menu",
+//! ));
+//! assert_eq!(
+//!     menu_button.to_string(),
+//!     "<button role=\"button\" aria-expanded=\"false\" aria-haspopup=\"menu\">Menu</button>"
+//! );
+//! ```
+
+value_enum! {
+    /// A value of the [`Role`](crate::Role) attribute
+    Role for Role {
+        Alert = "alert",
+        AlertDialog = "alertdialog",
+        Application = "application",
+        Article = "article",
+        Banner = "banner",
+        Blockquote = "blockquote",
+        Button = "button",
+        Caption = "caption",
+        Cell = "cell",
+        Checkbox = "checkbox",
+        Code = "code",
+        ColumnHeader = "columnheader",
+        Combobox = "combobox",
+        Complementary = "complementary",
+        ContentInfo = "contentinfo",
+        Definition = "definition",
+        Deletion = "deletion",
+        Dialog = "dialog",
+        Document = "document",
+        Emphasis = "emphasis",
+        Feed = "feed",
+        Figure = "figure",
+        Form = "form",
+        Generic = "generic",
+        Grid = "grid",
+        GridCell = "gridcell",
+        Group = "group",
+        Heading = "heading",
+        Img = "img",
+        Insertion = "insertion",
+        Link = "link",
+        List = "list",
+        Listbox = "listbox",
+        ListItem = "listitem",
+        Log = "log",
+        Main = "main",
+        Marquee = "marquee",
+        Math = "math",
+        Menu = "menu",
+        Menubar = "menubar",
+        MenuItem = "menuitem",
+        MenuItemCheckbox = "menuitemcheckbox",
+        MenuItemRadio = "menuitemradio",
+        Meter = "meter",
+        Navigation = "navigation",
+        None = "none",
+        Note = "note",
+        Option = "option",
+        Paragraph = "paragraph",
+        Presentation = "presentation",
+        ProgressBar = "progressbar",
+        Radio = "radio",
+        RadioGroup = "radiogroup",
+        Region = "region",
+        Row = "row",
+        RowGroup = "rowgroup",
+        RowHeader = "rowheader",
+        Scrollbar = "scrollbar",
+        Search = "search",
+        Searchbox = "searchbox",
+        Separator = "separator",
+        Slider = "slider",
+        SpinButton = "spinbutton",
+        Status = "status",
+        Strong = "strong",
+        Subscript = "subscript",
+        Superscript = "superscript",
+        Switch = "switch",
+        Tab = "tab",
+        Table = "table",
+        Tablist = "tablist",
+        Tabpanel = "tabpanel",
+        Term = "term",
+        Textbox = "textbox",
+        Time = "time",
+        Timer = "timer",
+        Toolbar = "toolbar",
+        Tooltip = "tooltip",
+        Tree = "tree",
+        TreeGrid = "treegrid",
+        TreeItem = "treeitem",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaAutocomplete`](crate::AriaAutocomplete) attribute
+    Autocomplete for AriaAutocomplete {
+        Inline = "inline",
+        List = "list",
+        Both = "both",
+        None = "none",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaChecked`](crate::AriaChecked) and [`AriaPressed`](crate::AriaPressed) attributes
+    Tristate for AriaChecked, AriaPressed {
+        True = "true",
+        False = "false",
+        Mixed = "mixed",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaCurrent`](crate::AriaCurrent) attribute
+    Current for AriaCurrent {
+        Page = "page",
+        Step = "step",
+        Location = "location",
+        Date = "date",
+        Time = "time",
+        True = "true",
+        False = "false",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaHaspopup`](crate::AriaHaspopup) attribute
+    HasPopup for AriaHaspopup {
+        False = "false",
+        True = "true",
+        Menu = "menu",
+        Listbox = "listbox",
+        Tree = "tree",
+        Grid = "grid",
+        Dialog = "dialog",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaInvalid`](crate::AriaInvalid) attribute
+    Invalid for AriaInvalid {
+        False = "false",
+        True = "true",
+        Grammar = "grammar",
+        Spelling = "spelling",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaLive`](crate::AriaLive) attribute
+    Live for AriaLive {
+        Off = "off",
+        Polite = "polite",
+        Assertive = "assertive",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaOrientation`](crate::AriaOrientation) attribute
+    Orientation for AriaOrientation {
+        Horizontal = "horizontal",
+        Vertical = "vertical",
+    }
+}
+
+value_enum! {
+    /// A token of the [`AriaRelevant`](crate::AriaRelevant) attribute
+    Relevant for AriaRelevant {
+        Additions = "additions",
+        All = "all",
+        Removals = "removals",
+        Text = "text",
+    }
+}
+
+value_enum! {
+    /// A value of the [`AriaSort`](crate::AriaSort) attribute
+    Sort for AriaSort {
+        Ascending = "ascending",
+        Descending = "descending",
+        None = "none",
+        Other = "other",
+    }
+}
@@ -22,35 +22,6 @@ impl<'a> GlobalAttributes<'a> {
     pub const EMPTY: Self = Self(None);
 }
 
-/// Attributes that are common to all elements
-///
-/// Wrapped by [`GlobalAttributes`]
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
-pub struct GlobalAttributesInner<'a> {
-    /// The `id` attribute
-    pub id: Cow<'a, str>,
-    /// The `class` attribute
-    pub class: Cow<'a, str>,
-    /// The `style` attribute
-    pub style: Cow<'a, str>,
-    /// The `title` attribute
-    pub title: Cow<'a, str>,
-    /// The `autofocus` attribute
-    pub autofocus: bool,
-    /// The `itemscope` attribute
-    pub itemscope: bool,
-}
-
-pub(crate) static DEFAULT_GLOBAL_ATTRIBUTES_INNER: GlobalAttributesInner<'static> =
-    GlobalAttributesInner {
-        id: Cow::Borrowed(""),
-        class: Cow::Borrowed(""),
-        style: Cow::Borrowed(""),
-        title: Cow::Borrowed(""),
-        autofocus: false,
-        itemscope: false,
-    };
-
 impl<'a> Deref for GlobalAttributes<'a> {
     type Target = GlobalAttributesInner<'a>;
     fn deref(&self) -> &Self::Target {
@@ -66,38 +37,1048 @@ impl<'a> DerefMut for GlobalAttributes<'a> {
     }
 }
 
-impl<'a> IndentFormat for GlobalAttributes<'a> {
+/// Add the name and value of an attribute to a list if it is set
+macro_rules! push_attr {
+    ($this:expr, $attrs:expr, attribute::$attr:ident) => {
+        paste! {
+            if let Some(value) = attribute::[<$attr _value>](&$this.$attr) {
+                $attrs.push((Cow::Borrowed(attribute::[<$attr:upper _NAME>]), value));
+            }
+        }
+    };
+    ($this:expr, $attrs:expr, $attr:ident) => {
+        paste! {
+            if let Some(value) = [<$attr _value>](&$this.$attr) {
+                $attrs.push((Cow::Borrowed([<$attr:upper _NAME>]), value));
+            }
+        }
+    };
+}
+
+macro_rules! global_attributes {
+    ($($name:ident),* $(,)?) => {
+        paste! {
+            /// Attributes that are common to all elements
+            ///
+            /// Wrapped by [`GlobalAttributes`]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+            pub struct GlobalAttributesInner<'a> {
+                $(
+                    #[doc = "The [`" [<$name:camel>] "`] attribute"]
+                    pub $name: [<$name _t>]<'a>,
+                )*
+                /// The `data-*` attributes
+                pub data: DataAttributes<'a>,
+            }
+
+            pub(crate) static DEFAULT_GLOBAL_ATTRIBUTES_INNER: GlobalAttributesInner<'static> =
+                GlobalAttributesInner {
+                    $($name: [<$name:upper _DEFAULT>],)*
+                    data: DataAttributes::NONE,
+                };
+
+            impl<'a> IndentFormat for GlobalAttributes<'a> {
+                fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+                    $([<$name _write>](&self.$name, f)?;)*
+                    self.data.indent_fmt(f)?;
+                    Ok(())
+                }
+            }
+
+            impl<'a> GlobalAttributes<'a> {
+                /// Add the names and values of the attributes that are set
+                pub(crate) fn collect<'s>(&'s self, attrs: &mut Vec<(Cow<'s, str>, Option<&'s str>)>) {
+                    $(push_attr!(self, attrs, $name);)*
+                    for (name, value) in self.data.iter() {
+                        attrs.push((Cow::Owned(format!("data-{name}")), Some(value)));
+                    }
+                }
+                /// Set a global attribute by name
+                ///
+                /// `None` is the value of an attribute that was given without one.
+                /// If there is no global attribute with the name, the value is given back.
+                pub(crate) fn set_by_name(
+                    &mut self,
+                    name: &str,
+                    value: Option<Cow<'a, str>>,
+                ) -> Result<(), Option<Cow<'a, str>>> {
+                    match name {
+                        $([<$name:upper _NAME>] => self.$name = [<$name _parse>](value),)*
+                        _ => return Err(value),
+                    }
+                    Ok(())
+                }
+            }
+        }
+    };
+}
+
+/// Define a wrapper around attributes that are common to a group of elements,
+/// like [`GlobalAttributes`], and a macro that implements their traits for an element
+macro_rules! attribute_group {
+    (
+        $(#[$doc:meta])*
+        $wrapper:ident($inner:ident),
+        $field:ident,
+        $impl_all:ident,
+        $($name:ident),* $(,)?
+    ) => {
+        paste! {
+            $(#[$doc])*
+            ///
+            #[doc = "`Deref`s (and `DerefMut`s) to [`" $inner "`]"]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+            pub struct $wrapper<'a>(Option<Box<$inner<'a>>>);
+
+            impl<'a> $wrapper<'a> {
+                /// No attributes
+                pub const EMPTY: Self = Self(None);
+            }
+
+            impl<'a> std::ops::Deref for $wrapper<'a> {
+                type Target = $inner<'a>;
+                fn deref(&self) -> &Self::Target {
+                    self.0.as_deref().unwrap_or(&[<$inner:snake:upper _DEFAULT>])
+                }
+            }
+
+            impl<'a> std::ops::DerefMut for $wrapper<'a> {
+                fn deref_mut(&mut self) -> &mut Self::Target {
+                    self.0.get_or_insert_with(std::default::Default::default)
+                }
+            }
+
+            #[doc = "Attributes wrapped by [`" $wrapper "`]"]
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+            pub struct $inner<'a> {
+                $(
+                    #[doc = "The [`" [<$name:camel>] "`] attribute"]
+                    pub $name: [<$name _t>]<'a>,
+                )*
+            }
+
+            static [<$inner:snake:upper _DEFAULT>]: $inner<'static> = $inner {
+                $($name: [<$name:upper _DEFAULT>],)*
+            };
+
+            impl<'a> IndentFormat for $wrapper<'a> {
+                fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+                    $([<$name _write>](&self.$name, f)?;)*
+                    Ok(())
+                }
+            }
+
+            impl<'a> $wrapper<'a> {
+                /// Add the names and values of the attributes that are set
+                pub(crate) fn collect<'s>(&'s self, attrs: &mut Vec<(Cow<'s, str>, Option<&'s str>)>) {
+                    $(push_attr!(self, attrs, $name);)*
+                }
+                /// Set an attribute by name
+                ///
+                /// If there is no attribute with the name in this group, the value is given back.
+                pub(crate) fn set_by_name(
+                    &mut self,
+                    name: &str,
+                    value: Option<Cow<'a, str>>,
+                ) -> Result<(), Option<Cow<'a, str>>> {
+                    match name {
+                        $([<$name:upper _NAME>] => self.$name = [<$name _parse>](value),)*
+                        _ => return Err(value),
+                    }
+                    Ok(())
+                }
+            }
+        }
+
+        /// Implement the attribute traits of every attribute in the group
+        macro_rules! $impl_all {
+            ($elem:ident) => {
+                $(
+                    paste! {
+                        impl<'a> [<Has $name:camel>]<'a> for $elem<'a> {
+                            fn [<get_ $name>](&self) -> [<$name _ref_t>]<'_> {
+                                [<$name _take_ref>](&self.$field.$name)
+                            }
+                            fn [<set_ $name>](&mut self, val: impl Into<[<$name _t>]<'a>>) {
+                                self.$field.$name = val.into();
+                            }
+                        }
+                    }
+                )*
+            };
+        }
+    };
+}
+
+global_attributes!(
+    id,
+    class,
+    style,
+    title,
+    autofocus,
+    itemscope,
+    role,
+    aria_activedescendant,
+    aria_atomic,
+    aria_autocomplete,
+    aria_busy,
+    aria_checked,
+    aria_colcount,
+    aria_colindex,
+    aria_colindextext,
+    aria_colspan,
+    aria_controls,
+    aria_current,
+    aria_describedby,
+    aria_description,
+    aria_details,
+    aria_disabled,
+    aria_errormessage,
+    aria_expanded,
+    aria_flowto,
+    aria_haspopup,
+    aria_hidden,
+    aria_invalid,
+    aria_keyshortcuts,
+    aria_label,
+    aria_labelledby,
+    aria_level,
+    aria_live,
+    aria_modal,
+    aria_multiline,
+    aria_multiselectable,
+    aria_orientation,
+    aria_owns,
+    aria_placeholder,
+    aria_posinset,
+    aria_pressed,
+    aria_readonly,
+    aria_relevant,
+    aria_required,
+    aria_roledescription,
+    aria_rowcount,
+    aria_rowindex,
+    aria_rowindextext,
+    aria_rowspan,
+    aria_selected,
+    aria_setsize,
+    aria_sort,
+    aria_valuemax,
+    aria_valuemin,
+    aria_valuenow,
+    aria_valuetext,
+    accesskey,
+    autocapitalize,
+    contenteditable,
+    dir,
+    draggable,
+    enterkeyhint,
+    exportparts,
+    hidden,
+    inert,
+    inputmode,
+    is,
+    itemid,
+    itemprop,
+    itemref,
+    itemtype,
+    lang,
+    nonce,
+    part,
+    popover,
+    slot,
+    spellcheck,
+    tabindex,
+    translate,
+);
+
+/// The `data-*` attributes of an element
+///
+/// Names are stored without the `data-` prefix.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct DataAttributes<'a>(Vec<(Cow<'a, str>, Cow<'a, str>)>);
+
+impl<'a> DataAttributes<'a> {
+    /// No data attributes
+    pub const NONE: Self = Self(Vec::new());
+    /// Check if there are no data attributes
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Check if there is a data attribute with the given name
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|(n, _)| n == name)
+    }
+    /// Get the value of the data attribute with the given name
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_ref())
+    }
+    /// Insert a data attribute
+    ///
+    /// If the attribute already exists, its value is replaced but it keeps its position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is not a [valid `data-*` name](is_valid_data_name).
+    /// Names are not lowercased for you.
+    pub fn insert(&mut self, name: impl Into<Cow<'a, str>>, value: impl Into<Cow<'a, str>>) {
+        let name = name.into();
+        assert!(
+            is_valid_data_name(&name),
+            "{name:?} is not a valid data-* attribute name"
+        );
+        if let Some(i) = self.0.iter().position(|(n, _)| n == &name) {
+            self.0[i].1 = value.into();
+        } else {
+            self.0.push((name, value.into()));
+        }
+    }
+    /// Remove the data attribute with the given name
+    pub fn remove(&mut self, name: &str) {
+        self.0.retain(|(n, _)| n != name);
+    }
+    /// Iterate over the data attributes in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.iter().map(|(n, v)| (n.as_ref(), v.as_ref()))
+    }
+}
+
+impl<'a> IndentFormat for DataAttributes<'a> {
+    fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+        for (name, value) in self.iter() {
+            f.write_attr(format_args!("data-{name}"), value)?;
+        }
+        Ok(())
+    }
+}
+
+/// The classes of an element
+///
+/// Classes are kept in the order they were added, without duplicates.
+/// Adding [`Class`] to an element more than once adds to its list,
+/// and [`Class::when`] only adds a class if a condition is true.
+///
+/// ```
+/// use hotman::*;
+///
+/// let selected = true;
+/// let item = li((Class("item"), Class::when(selected, "selected"), Class("item"), "Home"));
+/// assert_eq!(item.to_string(), "<li class=\"item selected\">Home</li>");
+/// ```
+///
+/// The list can also be changed directly.
+///
+/// ```
+/// use hotman::*;
+///
+/// let mut classes = ClassList::from("card card  wide");
+/// classes.add("active wide");
+/// classes.remove("card");
+/// assert_eq!(classes.as_str(), "wide active");
+/// assert!(classes.toggle("card"));
+/// assert_eq!(classes, "wide active card");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct ClassList<'a>(Cow<'a, str>);
+
+impl<'a> ClassList<'a> {
+    /// No classes
+    pub const NONE: Self = Self(Cow::Borrowed(""));
+    /// Check if there are no classes
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Check if the list contains a class
+    pub fn contains(&self, class: &str) -> bool {
+        self.iter().any(|c| c == class)
+    }
+    /// Iterate over the classes in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.split_ascii_whitespace()
+    }
+    /// Get the classes as a space-separated string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// Add classes
+    ///
+    /// The string can contain multiple classes separated by whitespace.
+    /// Classes that are already in the list are skipped.
+    pub fn add(&mut self, classes: impl Into<Cow<'a, str>>) {
+        let classes = classes.into();
+        if self.is_empty() {
+            *self = classes.into();
+            return;
+        }
+        for class in classes.split_ascii_whitespace() {
+            if !self.contains(class) {
+                let list = self.0.to_mut();
+                list.push(' ');
+                list.push_str(class);
+            }
+        }
+    }
+    /// Remove a class
+    pub fn remove(&mut self, class: &str) {
+        if self.contains(class) {
+            let classes: Vec<&str> = self.iter().filter(|&c| c != class).collect();
+            self.0 = Cow::Owned(classes.join(" "));
+        }
+    }
+    /// Remove a class if it is in the list, or add it if it is not
+    ///
+    /// Returns whether the class is now in the list.
+    pub fn toggle(&mut self, class: impl Into<Cow<'a, str>>) -> bool {
+        let class = class.into();
+        if self.contains(&class) {
+            self.remove(&class);
+            false
+        } else {
+            self.add(class);
+            true
+        }
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for ClassList<'a> {
+    fn from(classes: Cow<'a, str>) -> Self {
+        let mut seen = Vec::new();
+        let normalized = classes.split(' ').all(|class| {
+            let unique = !class.is_empty() && !seen.contains(&class);
+            seen.push(class);
+            unique && !class.contains(|c: char| c.is_ascii_whitespace())
+        });
+        if normalized || classes.is_empty() {
+            return ClassList(classes);
+        }
+        let mut list = String::new();
+        for class in classes.split_ascii_whitespace() {
+            if !list.split(' ').any(|c| c == class) {
+                if !list.is_empty() {
+                    list.push(' ');
+                }
+                list.push_str(class);
+            }
+        }
+        ClassList(Cow::Owned(list))
+    }
+}
+
+impl<'a> From<&'a str> for ClassList<'a> {
+    fn from(classes: &'a str) -> Self {
+        Cow::Borrowed(classes).into()
+    }
+}
+
+impl<'a> From<String> for ClassList<'a> {
+    fn from(classes: String) -> Self {
+        Cow::<'a, str>::Owned(classes).into()
+    }
+}
+
+impl<'a> From<&'a String> for ClassList<'a> {
+    fn from(classes: &'a String) -> Self {
+        classes.as_str().into()
+    }
+}
+
+impl<'a> PartialEq<str> for ClassList<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.iter().eq(other.split_ascii_whitespace())
+    }
+}
+
+impl<'a> PartialEq<&str> for ClassList<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> fmt::Display for ClassList<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The declarations of an element's inline style
+///
+/// Each property appears at most once. Setting a property that is already set replaces its value
+/// but keeps its position, so the last value wins, just as it would in CSS.
+///
+/// Values are escaped so that they can't end their declaration early or add other declarations.
+///
+/// ```
+/// use hotman::*;
+///
+/// let mut style = StyleDeclarations::from("color: blue; margin: 0;");
+/// style.add("padding: 1em; color: red");
+/// style.set("width", "10px; position: fixed");
+/// assert_eq!(style.get("color"), Some("red"));
+/// assert_eq!(style.as_str(), "color: red; margin: 0; padding: 1em; width: 10px\\; position: fixed");
+/// style.remove("margin");
+/// assert_eq!(style, "color: red; padding: 1em; width: 10px\\; position: fixed");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct StyleDeclarations<'a>(Cow<'a, str>);
+
+impl<'a> StyleDeclarations<'a> {
+    /// No declarations
+    pub const NONE: Self = Self(Cow::Borrowed(""));
+    /// Check if there are no declarations
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Check if a property is set
+    pub fn contains(&self, property: &str) -> bool {
+        self.get(property).is_some()
+    }
+    /// Get the value of a property
+    pub fn get(&self, property: &str) -> Option<&str> {
+        self.iter()
+            .find(|(name, _)| same_property(name, property))
+            .map(|(_, value)| value)
+    }
+    /// Iterate over the properties and their values in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        split_declarations(&self.0)
+    }
+    /// Get the declarations as a string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    /// Set the value of a property
+    ///
+    /// The value is escaped. An empty value removes the property.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the property is not a valid CSS property name.
+    pub fn set(&mut self, property: &str, value: impl AttributeValue<'a>) {
+        assert!(
+            is_valid_property_name(property),
+            "Invalid CSS property name: {property:?}"
+        );
+        let value = value.into_attribute_value();
+        let value = value.trim();
+        if value.is_empty() {
+            self.remove(property);
+        } else {
+            self.merge([(property, escape_style_value(value).as_ref())]);
+        }
+    }
+    /// Remove a property
+    pub fn remove(&mut self, property: &str) {
+        if self.contains(property) {
+            let declarations = join_declarations(
+                self.iter()
+                    .filter(|(name, _)| !same_property(name, property)),
+            );
+            self.0 = Cow::Owned(declarations);
+        }
+    }
+    /// Add declarations, like `"color: red; margin: 0"`
+    ///
+    /// Declarations that are not valid are skipped.
+    pub fn add(&mut self, declarations: impl Into<Cow<'a, str>>) {
+        let other = Self::from(declarations.into());
+        if self.is_empty() {
+            *self = other;
+        } else {
+            self.merge(other.iter());
+        }
+    }
+    fn merge<'b>(&mut self, declarations: impl IntoIterator<Item = (&'b str, &'b str)>) {
+        let mut merged: Vec<(&str, &str)> = self.iter().collect();
+        for (property, value) in declarations {
+            match merged
+                .iter_mut()
+                .find(|(name, _)| same_property(name, property))
+            {
+                Some(declaration) => declaration.1 = value,
+                None => merged.push((property, value)),
+            }
+        }
+        let declarations = join_declarations(merged);
+        self.0 = Cow::Owned(declarations);
+    }
+}
+
+/// Check if two property names refer to the same property
+///
+/// Custom properties are case-sensitive, while others are not.
+fn same_property(a: &str, b: &str) -> bool {
+    if a.starts_with("--") {
+        a == b
+    } else {
+        a.eq_ignore_ascii_case(b)
+    }
+}
+
+fn is_valid_property_name(name: &str) -> bool {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii();
+    let rest = match name.strip_prefix("--") {
+        Some(rest) => rest,
+        None => {
+            let name = name.strip_prefix('-').unwrap_or(name);
+            match name.chars().next() {
+                Some(c) if c.is_ascii_alphabetic() || c == '_' || !c.is_ascii() => name,
+                _ => return false,
+            }
+        }
+    };
+    !rest.is_empty() && rest.chars().all(is_name_char)
+}
+
+/// Split a declaration list into trimmed properties and values
+///
+/// Semicolons inside of strings, parentheses, and escapes don't end a declaration.
+/// Declarations without a property or value are skipped.
+fn split_declarations(s: &str) -> impl Iterator<Item = (&str, &str)> {
+    let mut declarations = Vec::new();
+    let mut start = 0;
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (c, quote) {
+            ('\\', _) => escaped = true,
+            (c, Some(open)) if c == open => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(c),
+            ('(', None) => depth += 1,
+            (')', None) => depth = depth.saturating_sub(1),
+            (';', None) if depth == 0 => {
+                declarations.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    declarations.push(&s[start..]);
+    declarations.into_iter().filter_map(|declaration| {
+        let (property, value) = declaration.split_once(':')?;
+        let (property, value) = (property.trim(), value.trim());
+        (!property.is_empty() && !value.is_empty()).then_some((property, value))
+    })
+}
+
+/// Escape a property value so that it can't end its declaration
+///
+/// Semicolons and braces outside of strings and parentheses are escaped,
+/// and unclosed strings and parentheses are closed.
+fn escape_style_value(value: &str) -> Cow<'_, str> {
+    let mut escaped_value = String::with_capacity(value.len());
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for c in value.chars() {
+        if escaped {
+            escaped = false;
+            escaped_value.push(c);
+            continue;
+        }
+        match (c, quote) {
+            ('\\', _) => {
+                escaped = true;
+                escaped_value.push(c);
+            }
+            (c, Some(open)) if c == open => {
+                quote = None;
+                escaped_value.push(c);
+            }
+            ('\n' | '\r' | '\x0c', Some(_)) => {
+                escaped_value.push_str(&format!("\\{:x} ", c as u32));
+            }
+            (_, Some(_)) => escaped_value.push(c),
+            ('"' | '\'', None) => {
+                quote = Some(c);
+                escaped_value.push(c);
+            }
+            ('(', None) => {
+                depth += 1;
+                escaped_value.push(c);
+            }
+            (')', None) if depth > 0 => {
+                depth -= 1;
+                escaped_value.push(c);
+            }
+            (';', None) if depth > 0 => escaped_value.push(c),
+            (';' | '{' | '}' | ')', None) => {
+                escaped_value.push('\\');
+                escaped_value.push(c);
+            }
+            _ => escaped_value.push(c),
+        }
+    }
+    if escaped {
+        escaped_value.push('\\');
+    }
+    escaped_value.extend(quote);
+    escaped_value.push_str(&")".repeat(depth));
+    if escaped_value == value {
+        Cow::Borrowed(value)
+    } else {
+        Cow::Owned(escaped_value)
+    }
+}
+
+fn join_declarations<'b>(declarations: impl IntoIterator<Item = (&'b str, &'b str)>) -> String {
+    let mut joined = String::new();
+    for (property, value) in declarations {
+        if !joined.is_empty() {
+            joined.push_str("; ");
+        }
+        joined.push_str(property);
+        joined.push_str(": ");
+        joined.push_str(value);
+    }
+    joined
+}
+
+impl<'a> From<Cow<'a, str>> for StyleDeclarations<'a> {
+    fn from(declarations: Cow<'a, str>) -> Self {
+        let mut merged: Vec<(&str, Cow<str>)> = Vec::new();
+        for (property, value) in split_declarations(&declarations) {
+            if !is_valid_property_name(property) {
+                continue;
+            }
+            let value = escape_style_value(value);
+            match merged
+                .iter_mut()
+                .find(|(name, _)| same_property(name, property))
+            {
+                Some(declaration) => declaration.1 = value,
+                None => merged.push((property, value)),
+            }
+        }
+        let joined = join_declarations(merged.iter().map(|(p, v)| (*p, v.as_ref())));
+        if joined == *declarations {
+            StyleDeclarations(declarations)
+        } else {
+            StyleDeclarations(Cow::Owned(joined))
+        }
+    }
+}
+
+impl<'a> From<&'a str> for StyleDeclarations<'a> {
+    fn from(declarations: &'a str) -> Self {
+        Cow::Borrowed(declarations).into()
+    }
+}
+
+impl<'a> From<String> for StyleDeclarations<'a> {
+    fn from(declarations: String) -> Self {
+        Cow::<'a, str>::Owned(declarations).into()
+    }
+}
+
+impl<'a> From<&'a String> for StyleDeclarations<'a> {
+    fn from(declarations: &'a String) -> Self {
+        declarations.as_str().into()
+    }
+}
+
+impl<'a> PartialEq<str> for StyleDeclarations<'a> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == StyleDeclarations::from(other).as_str()
+    }
+}
+
+impl<'a> PartialEq<&str> for StyleDeclarations<'a> {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl<'a> fmt::Display for StyleDeclarations<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Style {
+    /// Set a single property
+    ///
+    /// The value is escaped, so it is safe to pass user-supplied strings.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let color = "red";
+    /// let cell = td((
+    ///     Style("border: none; color: black"),
+    ///     Style::prop("color", color),
+    ///     Style::prop("text-align", "center"),
+    ///     "Total",
+    /// ));
+    /// assert_eq!(
+    ///     cell.to_string(),
+    ///     "<td style=\"border: none; color: red; text-align: center\">Total</td>"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the property is not a valid CSS property name.
+    pub fn prop<'a>(property: &str, value: impl AttributeValue<'a>) -> Self {
+        let mut declarations = StyleDeclarations::NONE;
+        declarations.set(property, value);
+        Style(declarations.to_string())
+    }
+}
+
+/// Add a `data-*` attribute to an element
+///
+/// The name should not include the `data-` prefix.
+///
+/// ```
+/// use hotman::*;
+///
+/// let widget = div((Data("widget", "carousel"), Data("interval", "5000")));
+/// assert_eq!(
+///     widget.to_string(),
+///     "<div data-widget=\"carousel\" data-interval=\"5000\"></div>"
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Adding this to an element panics if the name is not a [valid `data-*` name](is_valid_data_name).
+///
+/// ```should_panic
+/// use hotman::*;
+///
+/// div(Data("x\" onclick=\"alert(1)", "v"));
+/// ```
+///
+/// ```should_panic
+/// use hotman::*;
+///
+/// div(Data("fooBar", "1"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Data<N, V>(
+    /// The name, without the `data-` prefix
+    pub N,
+    /// The value
+    pub V,
+);
+
+impl<'a, E, N, V> ElementData<E> for Data<N, V>
+where
+    E: Element<'a>,
+    N: Into<Cow<'a, str>>,
+    V: Into<Cow<'a, str>>,
+{
+    fn add_to(self, element: &mut E) {
+        element.global_mut().data.insert(self.0, self.1);
+    }
+}
+
+/// Check if a string is a syntactically valid HTML attribute name
+///
+/// Attribute names must be non-empty and cannot contain whitespace, control characters,
+/// noncharacters, or any of `"`, `'`, `>`, `/`, and `=`.
+pub fn is_valid_attribute_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().all(|c| {
+            !(c.is_ascii_whitespace()
+                || c.is_control()
+                || matches!(c, '"' | '\'' | '>' | '/' | '=' | '\u{FDD0}'..='\u{FDEF}')
+                || (c as u32 & 0xFFFE) == 0xFFFE)
+        })
+}
+
+/// Check if a string is a valid name for a `data-*` attribute, without the `data-` prefix
+///
+/// It must be a [valid attribute name](is_valid_attribute_name) with no ASCII uppercase letters,
+/// since HTML lowercases attribute names when it parses them.
+pub fn is_valid_data_name(name: &str) -> bool {
+    is_valid_attribute_name(name) && !name.bytes().any(|b| b.is_ascii_uppercase())
+}
+
+/// Attributes without their own struct, added with [`Attr`] and [`BoolAttr`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct CustomAttributes<'a>(Vec<(Cow<'a, str>, Option<Cow<'a, str>>)>);
+
+impl<'a> CustomAttributes<'a> {
+    /// No custom attributes
+    pub const NONE: Self = Self(Vec::new());
+    /// Check if there are no custom attributes
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+    /// Check if there is a custom attribute with the given name
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.iter().any(|(n, _)| n == name)
+    }
+    /// Get the value of the custom attribute with the given name
+    ///
+    /// Boolean attributes have a value of `None`.
+    pub fn get(&self, name: &str) -> Option<Option<&str>> {
+        self.0
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_deref())
+    }
+    /// Insert a custom attribute
+    ///
+    /// A value of `None` makes it a boolean attribute.
+    /// If the attribute already exists, its value is replaced but it keeps its position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is not a [valid attribute name](is_valid_attribute_name).
+    pub fn insert(&mut self, name: impl Into<Cow<'a, str>>, value: Option<Cow<'a, str>>) {
+        let name = name.into();
+        assert!(
+            is_valid_attribute_name(&name),
+            "{name:?} is not a valid attribute name"
+        );
+        if let Some(i) = self.0.iter().position(|(n, _)| n == &name) {
+            self.0[i].1 = value;
+        } else {
+            self.0.push((name, value));
+        }
+    }
+    /// Remove the custom attribute with the given name
+    pub fn remove(&mut self, name: &str) {
+        self.0.retain(|(n, _)| n != name);
+    }
+    /// Iterate over the custom attributes in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.0.iter().map(|(n, v)| (n.as_ref(), v.as_deref()))
+    }
+    /// Add the names and values of the attributes
+    pub(crate) fn collect<'s>(&'s self, attrs: &mut Vec<(Cow<'s, str>, Option<&'s str>)>) {
+        attrs.extend(
+            self.iter()
+                .map(|(name, value)| (Cow::Borrowed(name), value)),
+        );
+    }
+}
+
+impl<'a> IndentFormat for CustomAttributes<'a> {
     fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
-        id_write(&self.id, f.f)?;
-        class_write(&self.class, f.f)?;
-        style_write(&self.style, f.f)?;
-        title_write(&self.title, f.f)?;
-        autofocus_write(&self.autofocus, f.f)?;
-        itemscope_write(&self.itemscope, f.f)?;
+        for (name, value) in self.iter() {
+            match value {
+                Some(value) => f.write_attr(name, value)?,
+                None => f.write_bool_attr(name)?,
+            }
+        }
         Ok(())
     }
 }
 
+/// Add an attribute that doesn't have its own struct
+///
+/// This is useful for framework attributes like `hx-get` or `x-data`.
+///
+/// ```
+/// use hotman::*;
+///
+/// let search = input((Attr("hx-get", "/search"), Attr("hx-trigger", "keyup"), BoolAttr("x-cloak")));
+/// assert_eq!(
+///     search.to_string(),
+///     "<input hx-get=\"/search\" hx-trigger=\"keyup\" x-cloak>"
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Adding this to an element panics if the name is not a [valid attribute name](is_valid_attribute_name).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Attr<N, V>(
+    /// The name
+    pub N,
+    /// The value
+    pub V,
+);
+
+impl<'a, E, N, V> ElementData<E> for Attr<N, V>
+where
+    E: Element<'a>,
+    N: Into<Cow<'a, str>>,
+    V: AttributeValue<'a>,
+{
+    fn add_to(self, element: &mut E) {
+        element
+            .custom_mut()
+            .insert(self.0, Some(self.1.into_attribute_value()));
+    }
+}
+
+/// Add a boolean attribute that doesn't have its own struct
+///
+/// See [`Attr`].
+///
+/// # Panics
+///
+/// Adding this to an element panics if the name is not a [valid attribute name](is_valid_attribute_name).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoolAttr<N>(
+    /// The name
+    pub N,
+);
+
+impl<'a, E, N> ElementData<E> for BoolAttr<N>
+where
+    E: Element<'a>,
+    N: Into<Cow<'a, str>>,
+{
+    fn add_to(self, element: &mut E) {
+        element.custom_mut().insert(self.0, None);
+    }
+}
+
+macro_rules! attribute_name {
+    ($name:tt) => {
+        stringify!($name)
+    };
+    ($name:tt = $html:literal) => {
+        $html
+    };
+}
+
 macro_rules! attribute_struct {
-    ($name:tt[bool]) => {
+    ($name:tt[bool], $html:expr) => {
         paste! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
             #[doc = "The `"]
-            #[doc = stringify!($name)]
+            #[doc = $html]
             #[doc = "` attribute"]
             pub struct [<$name:camel>];
             #[allow(non_camel_case_types)]
             pub(crate) type [<$name _t>]<'a> = bool;
             #[allow(non_camel_case_types)]
-            pub(crate) type [<$name _ref_t>] = bool;
+            pub(crate) type [<$name _ref_t>]<'a> = bool;
+            #[allow(dead_code)]
+            pub(crate) const [<$name:upper _DEFAULT>]: bool = false;
+            #[allow(dead_code)]
+            pub(crate) const [<$name:upper _NAME>]: &str = $html;
+            #[allow(non_snake_case, dead_code)]
+            pub(crate) fn [<$name _parse>](_: Option<Cow<str>>) -> bool {
+                true
+            }
             #[allow(non_snake_case)]
-            pub(crate) fn [<$name _take_ref>](val: &[<$name _t>]) -> [<$name _ref_t>] {
+            pub(crate) fn [<$name _take_ref>](val: &[<$name _t>]<'_>) -> [<$name _ref_t>]<'static> {
                 *val
             }
+            #[allow(non_snake_case, dead_code)]
+            pub(crate) fn [<$name _value>](b: &bool) -> Option<Option<&'static str>> {
+                b.then_some(None)
+            }
             #[allow(non_snake_case)]
-            pub(crate) fn [<$name _write>](b: &bool, f: &mut fmt::Formatter) -> fmt::Result {
+            pub(crate) fn [<$name _write>](b: &bool, f: &mut IndentFormatter) -> fmt::Result {
                 if *b {
-                    write!(f, " {}", stringify!($name).trim_end_matches('_'))
+                    f.write_bool_attr($html.trim_end_matches('_'))
                 } else {
                     Ok(())
                 }
@@ -109,28 +1090,89 @@ macro_rules! attribute_struct {
             }
         }
     };
-    ($name:tt) => {
+    ($name:tt[$list:ident], $html:expr) => {
         paste! {
             #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
             #[allow(non_camel_case_types)]
             #[doc = "The `"]
-            #[doc = stringify!($name)]
+            #[doc = $html]
+            #[doc = "` attribute"]
+            #[doc = ""]
+            #[doc = "Adding it more than once adds to its [`" $list "`]."]
+            pub struct [<$name:camel>]<T = String>(pub T);
+            #[allow(non_camel_case_types)]
+            pub(crate) type [<$name _t>]<'a> = $list<'a>;
+            #[allow(non_camel_case_types)]
+            pub(crate) type [<$name _ref_t>]<'a> = &'a $list<'a>;
+            #[allow(dead_code)]
+            pub(crate) const [<$name:upper _DEFAULT>]: $list<'static> = $list::NONE;
+            #[allow(dead_code)]
+            pub(crate) const [<$name:upper _NAME>]: &str = $html;
+            #[allow(non_snake_case, dead_code)]
+            pub(crate) fn [<$name _parse>](value: Option<Cow<str>>) -> $list {
+                value.map($list::from).unwrap_or_default()
+            }
+            #[allow(non_snake_case)]
+            pub(crate) fn [<$name _take_ref>]<'a>(val: &'a $list<'_>) -> [<$name _ref_t>]<'a> {
+                val
+            }
+            #[allow(non_snake_case, dead_code)]
+            pub(crate) fn [<$name _value>]<'a>(list: &'a $list<'_>) -> Option<Option<&'a str>> {
+                (!list.is_empty()).then_some(Some(list.as_str()))
+            }
+            #[allow(non_snake_case)]
+            pub(crate) fn [<$name _write>](list: &$list, f: &mut IndentFormatter) -> fmt::Result {
+                if list.is_empty() {
+                    Ok(())
+                } else {
+                    f.write_attr($html, list.as_str())
+                }
+            }
+            impl<T> [<$name:camel>]<T> {
+                fn take(self) -> T {
+                    self.0
+                }
+                /// Add the value only if the condition is true
+                pub fn when(condition: bool, value: T) -> Option<Self> {
+                    condition.then_some(Self(value))
+                }
+            }
+        }
+    };
+    ($name:tt, $html:expr) => {
+        paste! {
+            #[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+            #[allow(non_camel_case_types)]
+            #[doc = "The `"]
+            #[doc = $html]
             #[doc = "` attribute"]
             pub struct [<$name:camel>]<T = String>(pub T);
             #[allow(non_camel_case_types)]
             pub(crate) type [<$name _t>]<'a> = Cow<'a, str>;
             #[allow(non_camel_case_types)]
             pub(crate) type [<$name _ref_t>]<'a> = &'a str;
+            #[allow(dead_code)]
+            pub(crate) const [<$name:upper _DEFAULT>]: Cow<'static, str> = Cow::Borrowed("");
+            #[allow(dead_code)]
+            pub(crate) const [<$name:upper _NAME>]: &str = $html;
+            #[allow(non_snake_case, dead_code)]
+            pub(crate) fn [<$name _parse>](value: Option<Cow<str>>) -> Cow<str> {
+                value.unwrap_or_default()
+            }
             #[allow(non_snake_case)]
             pub(crate) fn [<$name _take_ref>]<'a>(val: &'a [<$name _t>]) -> [<$name _ref_t>]<'a> {
                 val
             }
+            #[allow(non_snake_case, dead_code)]
+            pub(crate) fn [<$name _value>](s: &str) -> Option<Option<&str>> {
+                (!s.is_empty()).then_some(Some(s))
+            }
             #[allow(non_snake_case)]
-            pub(crate) fn [<$name _write>](s: &str, f: &mut fmt::Formatter) -> fmt::Result {
+            pub(crate) fn [<$name _write>](s: &str, f: &mut IndentFormatter) -> fmt::Result {
                 if s.is_empty() {
                     Ok(())
                 } else {
-                    write!(f, " {}=\"{}\"", stringify!($name).trim_end_matches('_'), s)
+                    f.write_attr($html.trim_end_matches('_'), s)
                 }
             }
             impl<T> [<$name:camel>]<T> {
@@ -155,15 +1197,28 @@ macro_rules! attribute_trait {
             }
         }
     };
+    ($name:tt [$list:ident]) => {
+        paste! {
+            impl<'a, E, T> ElementData<E> for [<$name:camel>]<T>
+            where
+                E: Element<'a>,
+                T: AttributeValue<'a> + $crate::ValueOf<[<$name:camel>], E>,
+            {
+                fn add_to(self, element: &mut E) {
+                    element.global_mut().$name.add(self.take().into_attribute_value());
+                }
+            }
+        }
+    };
     ($name:tt) => {
         paste! {
             impl<'a, E, T> ElementData<E> for [<$name:camel>]<T>
             where
                 E: [<Has $name:camel>]<'a>,
-                T: Into<Cow<'a, str>>,
+                T: AttributeValue<'a> + $crate::ValueOf<[<$name:camel>], E>,
             {
                 fn add_to(self, element: &mut E) {
-                    element.[<set_ $name>](self.take());
+                    element.[<set_ $name>](self.take().into_attribute_value());
                 }
             }
         }
@@ -171,24 +1226,24 @@ macro_rules! attribute_trait {
 }
 
 macro_rules! attributes {
-    ($($name:tt $([$ty:ident])?),* $(,)?) => {
-        $(attribute_struct!($name $([$ty])*);)*
+    ($($name:tt $([$ty:ident])? $(= $html:literal)?),* $(,)?) => {
+        $(attribute_struct!($name $([$ty])*, attribute_name!($name $(= $html)?));)*
         pub mod attribute_traits {
             //! Traits that mark elements as having attributes
             use super::*;
             $(
                 paste! {
                     #[doc = "Trait for elements that have the `"]
-                    #[doc = stringify!($name)]
+                    #[doc = attribute_name!($name $(= $html)?)]
                     #[doc = "` attribute"]
                     #[allow(non_camel_case_types)]
                     pub trait [<Has $name:camel>]<'a> {
                         #[doc = "Get the value of the `"]
-                        #[doc = stringify!($name)]
+                        #[doc = attribute_name!($name $(= $html)?)]
                         #[doc = "` attribute"]
-                        fn [<get_ $name>](&self) -> [<$name _ref_t>];
+                        fn [<get_ $name>](&self) -> [<$name _ref_t>]<'_>;
                         #[doc = "Set the value of the `"]
-                        #[doc = stringify!($name)]
+                        #[doc = attribute_name!($name $(= $html)?)]
                         #[doc = "` attribute"]
                         fn [<set_ $name>](&mut self, value: impl Into<[<$name _t>]<'a>>);
                     }
@@ -201,28 +1256,80 @@ macro_rules! attributes {
 
 attributes!(
     accept,
+    accesskey,
     action,
     align,
     allow,
     alt,
+    aria_activedescendant = "aria-activedescendant",
+    aria_atomic = "aria-atomic",
+    aria_autocomplete = "aria-autocomplete",
+    aria_busy = "aria-busy",
+    aria_checked = "aria-checked",
+    aria_colcount = "aria-colcount",
+    aria_colindex = "aria-colindex",
+    aria_colindextext = "aria-colindextext",
+    aria_colspan = "aria-colspan",
+    aria_controls = "aria-controls",
+    aria_current = "aria-current",
+    aria_describedby = "aria-describedby",
+    aria_description = "aria-description",
+    aria_details = "aria-details",
+    aria_disabled = "aria-disabled",
+    aria_errormessage = "aria-errormessage",
+    aria_expanded = "aria-expanded",
+    aria_flowto = "aria-flowto",
+    aria_haspopup = "aria-haspopup",
+    aria_hidden = "aria-hidden",
+    aria_invalid = "aria-invalid",
+    aria_keyshortcuts = "aria-keyshortcuts",
+    aria_label = "aria-label",
+    aria_labelledby = "aria-labelledby",
+    aria_level = "aria-level",
+    aria_live = "aria-live",
+    aria_modal = "aria-modal",
+    aria_multiline = "aria-multiline",
+    aria_multiselectable = "aria-multiselectable",
+    aria_orientation = "aria-orientation",
+    aria_owns = "aria-owns",
+    aria_placeholder = "aria-placeholder",
+    aria_posinset = "aria-posinset",
+    aria_pressed = "aria-pressed",
+    aria_readonly = "aria-readonly",
+    aria_relevant = "aria-relevant",
+    aria_required = "aria-required",
+    aria_roledescription = "aria-roledescription",
+    aria_rowcount = "aria-rowcount",
+    aria_rowindex = "aria-rowindex",
+    aria_rowindextext = "aria-rowindextext",
+    aria_rowspan = "aria-rowspan",
+    aria_selected = "aria-selected",
+    aria_setsize = "aria-setsize",
+    aria_sort = "aria-sort",
+    aria_valuemax = "aria-valuemax",
+    aria_valuemin = "aria-valuemin",
+    aria_valuenow = "aria-valuenow",
+    aria_valuetext = "aria-valuetext",
+    as,
     async[bool],
+    autocapitalize,
     autocomplete,
     autofocus[bool],
     autoplay[bool],
     charset,
     checked[bool],
     cite,
-    class,
+    class[ClassList],
     clear,
     color,
     cols,
     colspan,
     command,
     content,
+    contenteditable,
     controls[bool],
     coords,
     crossorigin,
-    data,
     datetime,
     decoding,
     default[bool],
@@ -231,7 +1338,11 @@ attributes!(
     dirname,
     disabled[bool],
     download,
+    draggable,
     enctype,
+    enterkeyhint,
+    exportparts,
+    fetchpriority,
     for,
     form,
     formaction,
@@ -241,6 +1352,7 @@ attributes!(
     formtarget,
     headers,
     height,
+    hidden[bool],
     high,
     href,
     hreflang,
@@ -248,12 +1360,20 @@ attributes!(
     icon,
     id,
     importance,
+    inert[bool],
+    inputmode,
     integrity,
     intrinsicsize,
+    is,
     ismap[bool],
+    itemid,
+    itemprop,
+    itemref,
     itemscope[bool],
+    itemtype,
     kind,
     label,
+    lang,
     list,
     loading,
     loop[bool],
@@ -274,12 +1394,17 @@ attributes!(
     nonce,
     noshade,
     novalidate[bool],
+    object_data = "data",
     open[bool],
     optimum,
+    part,
     pattern,
     ping,
     placeholder,
     playsinline,
+    popover,
+    popovertarget,
+    popovertargetaction,
     poster,
     preload,
     profile,
@@ -289,24 +1414,30 @@ attributes!(
     rel,
     required[bool],
     reversed[bool],
+    role,
     rows,
     rowspan,
     sandbox,
     scope,
     selected[bool],
+    shadowrootmode,
     shape,
     size,
     sizes,
+    slot,
     span,
+    spellcheck,
     src,
     srcdoc,
     srclang,
     srcset,
     start,
     step,
-    style,
+    style[StyleDeclarations],
+    tabindex,
     target,
     title,
+    translate,
     type,
     usemap,
     value,
@@ -326,12 +1457,25 @@ macro_rules! event {
             $($name,)*
         }
 
-        impl fmt::Display for Event {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl Event {
+            /// Get the name of the event handler attribute, like `onclick`
+            pub fn attribute_name(&self) -> &'static str {
                 match self {
-                    $(Self::$name => write!(f, paste!(concat!("on", stringify!([<$name:lower>])))),)*
+                    $(Self::$name => paste!(concat!("on", stringify!([<$name:lower>]))),)*
                 }
             }
+            /// Get the event with the given handler attribute name
+            pub fn from_attribute_name(name: &str) -> Option<Event> {
+                [$(Self::$name),*]
+                    .into_iter()
+                    .find(|event| event.attribute_name() == name)
+            }
+        }
+
+        impl fmt::Display for Event {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.attribute_name())
+            }
         }
     };
 }
@@ -448,6 +1592,22 @@ impl<'a> Events<'a> {
     pub fn iter(&self) -> impl Iterator<Item = (Event, &str)> {
         self.0.iter().map(|(n, v)| (*n, v.as_ref()))
     }
+    /// Add the names and values of the event attributes
+    pub(crate) fn collect<'s>(&'s self, attrs: &mut Vec<(Cow<'s, str>, Option<&'s str>)>) {
+        attrs.extend(
+            self.iter()
+                .map(|(event, value)| (Cow::Borrowed(event.attribute_name()), Some(value))),
+        );
+    }
+}
+
+impl<'a> IndentFormat for Events<'a> {
+    fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+        for (event, value) in self.iter() {
+            f.write_attr(event, value)?;
+        }
+        Ok(())
+    }
 }
 
 /// Add an event handler to an element
@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use crate::{element_structs::Html, Diagnostic, DiagnosticKind, GlobalAttributes, Node, Page};
+
+impl<'a> Node<'a> {
+    /// Check this node and its descendants for common accessibility problems
+    ///
+    /// This checks for:
+    /// - `img` elements without `alt` text
+    /// - `input`, `select`, and `textarea` elements without a label.
+    ///   A control is labeled if it is inside of a `label`, if a `label`'s `for` matches its `id`,
+    ///   or if it has an `aria-label` or `aria-labelledby`.
+    /// - Headings that skip a level, like an `h3` after an `h1`
+    /// - Links and buttons without text, `alt` text, or an `aria-label`
+    /// - `html` elements without a `lang`
+    /// - `tabindex` values greater than 0
+    ///
+    /// Diagnostics are in document order.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let node: Node = form((
+    ///     label((For("name"), "Name")),
+    ///     input((Id("name"), Type("text"))),
+    ///     input((Type("email"), Tabindex("2"))),
+    ///     button(img(Src("/send.png"))),
+    /// ))
+    /// .into();
+    /// let diagnostics = node.audit();
+    ///
+    /// assert_eq!(diagnostics.len(), 4);
+    /// assert_eq!(diagnostics[0].to_string(), "tabindex 2 is greater than 0 at /2");
+    /// assert_eq!(diagnostics[1].to_string(), "<input> has no label at /2");
+    /// assert_eq!(diagnostics[2].to_string(), "<button> has no accessible text at /3");
+    /// assert_eq!(diagnostics[3].to_string(), "<img> is missing the alt attribute at /3/0");
+    /// ```
+    pub fn audit(&self) -> Vec<Diagnostic> {
+        let mut auditor = Auditor::default();
+        auditor.node(self);
+        auditor.finish()
+    }
+}
+
+impl<'a> Page<'a> {
+    /// Check the document for common accessibility problems
+    ///
+    /// See [`Node::audit`].
+    pub fn audit(&self) -> Vec<Diagnostic> {
+        let mut auditor = Auditor::default();
+        auditor.global(&self.0.global);
+        auditor.html(&self.0);
+        auditor.children(&self.0.children);
+        auditor.finish()
+    }
+}
+
+#[derive(Default)]
+struct Auditor<'n> {
+    path: Vec<usize>,
+    /// The number of `label` elements that the current node is inside of
+    labels: usize,
+    /// The `for` attributes of every `label`
+    label_targets: HashSet<&'n str>,
+    /// The paths, `id`s, and tags of controls that may only be labeled by a `for` attribute
+    controls: Vec<(Vec<usize>, &'n str, &'static str)>,
+    previous_heading: Option<u8>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Get the level of a heading element
+fn heading_level(node: &Node) -> Option<u8> {
+    Some(match node {
+        Node::H1(_) => 1,
+        Node::H2(_) => 2,
+        Node::H3(_) => 3,
+        Node::H4(_) => 4,
+        Node::H5(_) => 5,
+        Node::H6(_) => 6,
+        _ => return None,
+    })
+}
+
+/// Check if an element has a label for assistive technology
+fn has_aria_label(global: &GlobalAttributes) -> bool {
+    !global.aria_label.trim().is_empty() || !global.aria_labelledby.trim().is_empty()
+}
+
+/// Check if nodes contain text that assistive technology can announce
+fn has_accessible_text(children: &[Node]) -> bool {
+    children.iter().any(|child| match child {
+        Node::Text(text) | Node::Raw(text) => !text.trim().is_empty(),
+        Node::Comment(_) => false,
+        Node::Img(img) => !img.alt.trim().is_empty() || has_aria_label(&img.global),
+        _ => child.global().is_some_and(|global| {
+            !global.aria_hidden.eq_ignore_ascii_case("true")
+                && (has_aria_label(global) || has_accessible_text(child.children().unwrap_or(&[])))
+        }),
+    })
+}
+
+impl<'n> Auditor<'n> {
+    fn report(&mut self, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic {
+            path: self.path.clone(),
+            kind,
+        });
+    }
+    fn node(&mut self, node: &'n Node<'_>) {
+        let Some(global) = node.global() else {
+            return;
+        };
+        self.global(global);
+        match node {
+            Node::Html(html) => self.html(html),
+            Node::Img(img) => {
+                if img.alt.trim().is_empty() {
+                    self.report(DiagnosticKind::MissingAttribute {
+                        element: "img",
+                        attribute: "alt",
+                    });
+                }
+            }
+            Node::Input(input) => {
+                let hidden = ["hidden", "submit", "reset", "button", "image"]
+                    .iter()
+                    .any(|ty| input.r#type.eq_ignore_ascii_case(ty));
+                if !hidden {
+                    self.control("input", global);
+                }
+            }
+            Node::Select(_) => self.control("select", global),
+            Node::Textarea(_) => self.control("textarea", global),
+            Node::Label(label) => {
+                if !label.r#for.is_empty() {
+                    self.label_targets.insert(&label.r#for);
+                }
+            }
+            Node::A(a) => {
+                if !a.href.is_empty()
+                    && !has_aria_label(global)
+                    && !has_accessible_text(&a.children)
+                {
+                    self.report(DiagnosticKind::MissingAccessibleText("a"));
+                }
+            }
+            Node::Button(button) => {
+                if !has_aria_label(global) && !has_accessible_text(&button.children) {
+                    self.report(DiagnosticKind::MissingAccessibleText("button"));
+                }
+            }
+            _ => {
+                if let Some(level) = heading_level(node) {
+                    if let Some(previous) = self.previous_heading.filter(|&p| level > p + 1) {
+                        self.report(DiagnosticKind::SkippedHeadingLevel { previous, level });
+                    }
+                    self.previous_heading = Some(level);
+                }
+            }
+        }
+        let label = matches!(node, Node::Label(_));
+        self.labels += label as usize;
+        self.children(node.children().unwrap_or(&[]));
+        self.labels -= label as usize;
+    }
+    fn global(&mut self, global: &GlobalAttributes) {
+        if global.tabindex.trim().parse::<i64>().is_ok_and(|i| i > 0) {
+            self.report(DiagnosticKind::PositiveTabindex(
+                global.tabindex.to_string(),
+            ));
+        }
+    }
+    fn html(&mut self, html: &Html) {
+        if html.global.lang.trim().is_empty() {
+            self.report(DiagnosticKind::MissingAttribute {
+                element: "html",
+                attribute: "lang",
+            });
+        }
+    }
+    fn control(&mut self, tag: &'static str, global: &'n GlobalAttributes<'_>) {
+        if self.labels == 0 && !has_aria_label(global) {
+            self.controls.push((self.path.clone(), &global.id, tag));
+        }
+    }
+    fn children(&mut self, children: &'n [Node<'_>]) {
+        for (i, child) in children.iter().enumerate() {
+            self.path.push(i);
+            self.node(child);
+            self.path.pop();
+        }
+    }
+    fn finish(mut self) -> Vec<Diagnostic> {
+        for (path, id, tag) in self.controls {
+            if id.is_empty() || !self.label_targets.contains(id) {
+                self.diagnostics.push(Diagnostic {
+                    path,
+                    kind: DiagnosticKind::UnlabeledControl(tag),
+                });
+            }
+        }
+        self.diagnostics.sort_by(|a, b| a.path.cmp(&b.path));
+        self.diagnostics
+    }
+}
@@ -0,0 +1,475 @@
+//! Content categories for the `strict` feature
+//!
+//! With the `strict` feature enabled, elements only accept the children that the
+//! [HTML content model](https://html.spec.whatwg.org/multipage/dom.html#content-models) permits.
+//! Anything else is a type error.
+//!
+//! **Warning:** this feature is not additive. Because features are unified across a build,
+//! enabling it also changes `hotman` for every other crate in the build that depends on it,
+//! which can break code that adds children the content model doesn't permit.
+//! Only enable it in a final binary, never in a library.
+//!
+//! Most elements take all [`FlowContent`] or all [`PhrasingContent`].
+//! Others only take specific elements, like [`ul`](crate::ul), which only takes [`li`](crate::li).
+//!
+//! ```
+//! use hotman::*;
+//!
+//! let list = ul((li("one"), li(p("two"))));
+//! assert_eq!(list.to_string(), "\
+//! <ul>
+//!     <li>one</li>
+//!     <li><p>two</p></li>
+//! </ul>");
+//! ```
+//!
+//! ```compile_fail
+//! use hotman::*;
+//!
+//! let list = ul(p("not a list item"));
+//! ```
+//!
+//! Elements with a [transparent](https://html.spec.whatwg.org/multipage/dom.html#transparent-content-models)
+//! content model, like [`a`](crate::a) and [`ins`](crate::ins), take flow content.
+//! [`area`](crate::area) is phrasing content, so it can be placed in a [`map`](crate::map).
+//!
+//! ```
+//! use hotman::*;
+//!
+//! let links = map((Name("m"), area((Shape("rect"), Coords("0,0,10,10"), Href("/")))));
+//! ```
+//!
+//! [`Comment`]s and [`Raw`] markup are accepted anywhere elements are.
+//! A [`Node`] is not checked, so converting a child into a `Node` opts out of checking.
+
+use std::borrow::Cow;
+
+use crate::{element_structs::*, Comment, ElementData, Node, ParentElement, Raw};
+
+/// Content that can be placed in the body of a document
+///
+/// See [flow content](https://html.spec.whatwg.org/multipage/dom.html#flow-content).
+pub trait FlowContent<'a>: Into<Node<'a>> {}
+
+/// The text of a document and the elements that mark it up
+///
+/// See [phrasing content](https://html.spec.whatwg.org/multipage/dom.html#phrasing-content).
+pub trait PhrasingContent<'a>: FlowContent<'a> {}
+
+/// Content that sets up the presentation or behavior of a document
+///
+/// See [metadata content](https://html.spec.whatwg.org/multipage/dom.html#metadata-content).
+pub trait MetadataContent<'a>: Into<Node<'a>> {}
+
+/// Put elements in a content category
+macro_rules! category {
+    ($category:ident: $($name:ty),* $(,)?) => {
+        $(impl<'a> $category<'a> for $name {})*
+    };
+}
+
+/// Let elements take any children in a content category
+macro_rules! category_children {
+    ($category:ident: $($parent:ident),* $(,)?) => {
+        $(
+            impl<'a, D> ElementData<$parent<'a>> for D
+            where
+                D: $category<'a>,
+            {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+        )*
+    };
+}
+
+/// Let an element take specific children
+macro_rules! children {
+    ($parent:ident: $($child:ty),* $(,)?) => {
+        $(
+            impl<'a> ElementData<$parent<'a>> for $child {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+        )*
+    };
+}
+
+/// Let elements that only take specific children also take comments, raw markup, and nodes
+macro_rules! unchecked_children {
+    ($($parent:ident),* $(,)?) => {
+        $(
+            impl<'a> ElementData<$parent<'a>> for Node<'a> {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self);
+                }
+            }
+
+            impl<'a, T> ElementData<$parent<'a>> for Comment<T>
+            where
+                T: Into<Cow<'a, str>>,
+            {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+
+            impl<'a, T> ElementData<$parent<'a>> for Raw<T>
+            where
+                T: Into<Cow<'a, str>>,
+            {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+        )*
+    };
+}
+
+/// Let elements take only text
+macro_rules! text_children {
+    ($($parent:ident),* $(,)?) => {
+        $(
+            impl<'a> ElementData<$parent<'a>> for String {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+
+            impl<'a> ElementData<$parent<'a>> for &'a str {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+
+            impl<'a> ElementData<$parent<'a>> for &'a String {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+
+            impl<'a> ElementData<$parent<'a>> for Node<'a> {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self);
+                }
+            }
+
+            impl<'a, T> ElementData<$parent<'a>> for Raw<T>
+            where
+                T: Into<Cow<'a, str>>,
+            {
+                fn add_to(self, elem: &mut $parent<'a>) {
+                    elem.children_mut().push(self.into());
+                }
+            }
+        )*
+    };
+}
+
+impl<'a> FlowContent<'a> for Node<'a> {}
+impl<'a> PhrasingContent<'a> for Node<'a> {}
+impl<'a> MetadataContent<'a> for Node<'a> {}
+impl<'a, T> FlowContent<'a> for Comment<T> where T: Into<Cow<'a, str>> {}
+impl<'a, T> PhrasingContent<'a> for Comment<T> where T: Into<Cow<'a, str>> {}
+impl<'a, T> MetadataContent<'a> for Comment<T> where T: Into<Cow<'a, str>> {}
+impl<'a, T> FlowContent<'a> for Raw<T> where T: Into<Cow<'a, str>> {}
+impl<'a, T> PhrasingContent<'a> for Raw<T> where T: Into<Cow<'a, str>> {}
+impl<'a, T> MetadataContent<'a> for Raw<T> where T: Into<Cow<'a, str>> {}
+
+category!(FlowContent: String, &'a str, &'a String);
+category!(PhrasingContent: String, &'a str, &'a String);
+
+category!(
+    FlowContent: A<'a>,
+    Abbr<'a>,
+    Address<'a>,
+    Area<'a>,
+    Article<'a>,
+    Aside<'a>,
+    Audio<'a>,
+    B<'a>,
+    Bdi<'a>,
+    Bdo<'a>,
+    Blockquote<'a>,
+    Br<'a>,
+    Button<'a>,
+    Canvas<'a>,
+    Cite<'a>,
+    Code<'a>,
+    Data<'a>,
+    Datalist<'a>,
+    Del<'a>,
+    Details<'a>,
+    Dfn<'a>,
+    Dialog<'a>,
+    Div<'a>,
+    Dl<'a>,
+    Em<'a>,
+    Embed<'a>,
+    Fieldset<'a>,
+    Figure<'a>,
+    Footer<'a>,
+    Form<'a>,
+    H1<'a>,
+    H2<'a>,
+    H3<'a>,
+    H4<'a>,
+    H5<'a>,
+    H6<'a>,
+    Header<'a>,
+    Hgroup<'a>,
+    Hr<'a>,
+    I<'a>,
+    Iframe<'a>,
+    Img<'a>,
+    Input<'a>,
+    Ins<'a>,
+    Kbd<'a>,
+    Label<'a>,
+    Main<'a>,
+    Map<'a>,
+    Mark<'a>,
+    Menu<'a>,
+    Meter<'a>,
+    Nav<'a>,
+    Noscript<'a>,
+    Object<'a>,
+    Ol<'a>,
+    Output<'a>,
+    P<'a>,
+    Picture<'a>,
+    Pre<'a>,
+    Progress<'a>,
+    Q<'a>,
+    Ruby<'a>,
+    S<'a>,
+    Samp<'a>,
+    Script<'a>,
+    Search<'a>,
+    Section<'a>,
+    Select<'a>,
+    Slot<'a>,
+    Small<'a>,
+    Span<'a>,
+    Strong<'a>,
+    Sub<'a>,
+    Sup<'a>,
+    Table<'a>,
+    Template<'a>,
+    Textarea<'a>,
+    Time<'a>,
+    U<'a>,
+    Ul<'a>,
+    Var<'a>,
+    Video<'a>,
+    Wbr<'a>,
+    crate::CustomElement<'a>,
+    crate::svg::element_structs::Svg<'a>,
+    crate::mathml::element_structs::Math<'a>,
+);
+
+category!(
+    PhrasingContent: A<'a>,
+    Abbr<'a>,
+    Area<'a>,
+    Audio<'a>,
+    B<'a>,
+    Bdi<'a>,
+    Bdo<'a>,
+    Br<'a>,
+    Button<'a>,
+    Canvas<'a>,
+    Cite<'a>,
+    Code<'a>,
+    Data<'a>,
+    Datalist<'a>,
+    Del<'a>,
+    Dfn<'a>,
+    Em<'a>,
+    Embed<'a>,
+    I<'a>,
+    Iframe<'a>,
+    Img<'a>,
+    Input<'a>,
+    Ins<'a>,
+    Kbd<'a>,
+    Label<'a>,
+    Map<'a>,
+    Mark<'a>,
+    Meter<'a>,
+    Noscript<'a>,
+    Object<'a>,
+    Output<'a>,
+    Picture<'a>,
+    Progress<'a>,
+    Q<'a>,
+    Ruby<'a>,
+    S<'a>,
+    Samp<'a>,
+    Script<'a>,
+    Select<'a>,
+    Slot<'a>,
+    Small<'a>,
+    Span<'a>,
+    Strong<'a>,
+    Sub<'a>,
+    Sup<'a>,
+    Template<'a>,
+    Textarea<'a>,
+    Time<'a>,
+    U<'a>,
+    Var<'a>,
+    Video<'a>,
+    Wbr<'a>,
+    crate::CustomElement<'a>,
+    crate::svg::element_structs::Svg<'a>,
+    crate::mathml::element_structs::Math<'a>,
+);
+
+category!(
+    MetadataContent: Base<'a>,
+    Link<'a>,
+    Meta<'a>,
+    Noscript<'a>,
+    Script<'a>,
+    Style<'a>,
+    Template<'a>,
+    Title<'a>,
+);
+
+category_children!(
+    FlowContent: A,
+    Address,
+    Article,
+    Aside,
+    Audio,
+    Blockquote,
+    Body,
+    Canvas,
+    Caption,
+    Dd,
+    Del,
+    Details,
+    Dialog,
+    Div,
+    Dt,
+    Fieldset,
+    Figcaption,
+    Figure,
+    Footer,
+    Form,
+    Header,
+    Ins,
+    Li,
+    Main,
+    Map,
+    Menuitem,
+    Nav,
+    Noscript,
+    Object,
+    Search,
+    Section,
+    Slot,
+    Td,
+    Template,
+    Th,
+    Video,
+);
+
+category_children!(
+    PhrasingContent: Abbr,
+    B,
+    Bdi,
+    Bdo,
+    Button,
+    Cite,
+    Code,
+    Data,
+    Datalist,
+    Dfn,
+    Em,
+    H1,
+    H2,
+    H3,
+    H4,
+    H5,
+    H6,
+    I,
+    Kbd,
+    Label,
+    Legend,
+    Mark,
+    Meter,
+    Output,
+    P,
+    Pre,
+    Progress,
+    Q,
+    Rp,
+    Rt,
+    Ruby,
+    S,
+    Samp,
+    Small,
+    Span,
+    Strong,
+    Sub,
+    Summary,
+    Sup,
+    Time,
+    U,
+    Var,
+);
+
+category_children!(MetadataContent: Head);
+
+impl<'a, D> ElementData<crate::CustomElement<'a>> for D
+where
+    D: FlowContent<'a>,
+{
+    fn add_to(self, elem: &mut crate::CustomElement<'a>) {
+        elem.children_mut().push(self.into());
+    }
+}
+
+children!(Audio: Source<'a>, Track<'a>);
+children!(Colgroup: Col<'a>, Template<'a>);
+children!(Datalist: Option<'a>);
+children!(Details: Summary<'a>);
+children!(Dl: Dt<'a>, Dd<'a>, Div<'a>, Script<'a>, Template<'a>);
+children!(Fieldset: Legend<'a>);
+children!(Figure: Figcaption<'a>);
+children!(Hgroup: H1<'a>, H2<'a>, H3<'a>, H4<'a>, H5<'a>, H6<'a>, P<'a>);
+children!(Html: Head<'a>, Body<'a>);
+children!(Menu: Li<'a>, Script<'a>, Template<'a>);
+children!(Object: Param<'a>);
+children!(Ol: Li<'a>, Script<'a>, Template<'a>);
+children!(Optgroup: Option<'a>, Script<'a>, Template<'a>);
+children!(Picture: Source<'a>, Img<'a>, Script<'a>, Template<'a>);
+children!(Ruby: Rp<'a>, Rt<'a>);
+children!(Select: Option<'a>, Optgroup<'a>, Hr<'a>, Script<'a>, Template<'a>);
+children!(
+    Table: Caption<'a>,
+    Colgroup<'a>,
+    Thead<'a>,
+    Tbody<'a>,
+    Tfoot<'a>,
+    Tr<'a>,
+    Script<'a>,
+    Template<'a>,
+);
+children!(Tbody: Tr<'a>, Script<'a>, Template<'a>);
+children!(Tfoot: Tr<'a>, Script<'a>, Template<'a>);
+children!(Thead: Tr<'a>, Script<'a>, Template<'a>);
+children!(Tr: Td<'a>, Th<'a>, Script<'a>, Template<'a>);
+children!(Ul: Li<'a>, Script<'a>, Template<'a>);
+children!(Video: Source<'a>, Track<'a>);
+
+unchecked_children!(
+    Colgroup, Dl, Hgroup, Html, Menu, Ol, Optgroup, Picture, Select, Table, Tbody, Tfoot, Thead,
+    Tr, Ul,
+);
+
+text_children!(Option, Script, Style, Textarea, Title);
@@ -3,7 +3,7 @@ use std::{borrow::Cow, fmt};
 use paste::paste;
 
 use crate::{
-    attribute::{self, Events, GlobalAttributes},
+    attribute::{self, CustomAttributes, Event, Events, GlobalAttributes},
     attribute_traits,
     format::*,
     ElementData,
@@ -14,13 +14,83 @@ pub const BR: element_structs::Br<'static> = element_structs::Br {
     global: GlobalAttributes::EMPTY,
     events: Events::NONE,
     clear: Cow::Borrowed(""),
-    children: Vec::new(),
+    custom: CustomAttributes::NONE,
 };
 
 /// Trait for types of elements
 pub trait Element<'a> {
+    /// Get the global attributes of this element
+    fn global(&self) -> &GlobalAttributes<'a>;
+    /// Get the mutable global attributes of this element
+    fn global_mut(&mut self) -> &mut GlobalAttributes<'a>;
     /// Get the mutable events of this element
     fn events_mut(&mut self) -> &mut Events<'a>;
+    /// Get the mutable custom attributes of this element
+    fn custom_mut(&mut self) -> &mut CustomAttributes<'a>;
+    /// Add classes to this element
+    ///
+    /// The string can contain multiple classes separated by whitespace.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let mut item = li((Class("item"), "Home"));
+    /// item.add_class("active item");
+    /// assert!(item.has_class("active"));
+    /// item.remove_class("item");
+    /// assert!(!item.toggle_class("active"));
+    /// assert!(item.toggle_class("current"));
+    /// assert_eq!(item.to_string(), "<li class=\"current\">Home</li>");
+    /// ```
+    fn add_class(&mut self, class: impl Into<Cow<'a, str>>)
+    where
+        Self: Sized,
+    {
+        self.global_mut().class.add(class);
+    }
+    /// Remove a class from this element
+    fn remove_class(&mut self, class: &str) {
+        if self.has_class(class) {
+            self.global_mut().class.remove(class);
+        }
+    }
+    /// Remove a class from this element if it has it, or add it if it doesn't
+    ///
+    /// Returns whether the element now has the class.
+    fn toggle_class(&mut self, class: impl Into<Cow<'a, str>>) -> bool
+    where
+        Self: Sized,
+    {
+        self.global_mut().class.toggle(class)
+    }
+    /// Check if this element has a class
+    fn has_class(&self, class: &str) -> bool {
+        self.global().class.contains(class)
+    }
+}
+
+/// Trait for elements that can have children
+///
+/// This is implemented for every element except the
+/// [void elements](https://developer.mozilla.org/en-US/docs/Glossary/Void_element),
+/// so trying to add a child to a void element is a compile error.
+///
+/// ```compile_fail
+/// use hotman::*;
+///
+/// let line_break = br("text");
+/// ```
+///
+/// Void elements are rendered without a closing tag,
+/// while other elements always have one.
+///
+/// ```
+/// use hotman::*;
+///
+/// assert_eq!(img(Src("/cat.png")).to_string(), "<img src=\"/cat.png\">");
+/// assert_eq!(div(()).to_string(), "<div></div>");
+/// ```
+pub trait ParentElement<'a>: Element<'a> {
     /// Get the mutable children of this element
     fn children_mut(&mut self) -> &mut Vec<Node<'a>>;
 }
@@ -29,11 +99,11 @@ macro_rules! impl_global_attrs {
     ($name:ident, $($attr:ident),* $(,)?) => {
         $(
             paste! {
-                impl<'a> attribute_traits::[<Has $attr:camel>]<'a> for $name<'a> {
-                    fn [<get_ $attr>](&self) -> attribute::[<$attr _ref_t>] {
-                        attribute::[<$attr _take_ref>](&self.global.$attr)
+                impl<'a> $crate::attribute_traits::[<Has $attr:camel>]<'a> for $name<'a> {
+                    fn [<get_ $attr>](&self) -> $crate::attribute::[<$attr _ref_t>]<'_> {
+                        $crate::attribute::[<$attr _take_ref>](&self.global.$attr)
                     }
-                    fn [<set_ $attr>](&mut self, val: impl Into<attribute::[<$attr _t>]<'a>>) {
+                    fn [<set_ $attr>](&mut self, val: impl Into<$crate::attribute::[<$attr _t>]<'a>>) {
                         self.global.$attr = val.into();
                     }
                 }
@@ -42,103 +112,913 @@ macro_rules! impl_global_attrs {
     }
 }
 
+/// Implement the attribute traits of every global attribute
+macro_rules! impl_all_global_attrs {
+    ($name:ident) => {
+        impl_global_attrs!(
+            $name,
+            id,
+            class,
+            style,
+            title,
+            autofocus,
+            itemscope,
+            role,
+            aria_activedescendant,
+            aria_atomic,
+            aria_autocomplete,
+            aria_busy,
+            aria_checked,
+            aria_colcount,
+            aria_colindex,
+            aria_colindextext,
+            aria_colspan,
+            aria_controls,
+            aria_current,
+            aria_describedby,
+            aria_description,
+            aria_details,
+            aria_disabled,
+            aria_errormessage,
+            aria_expanded,
+            aria_flowto,
+            aria_haspopup,
+            aria_hidden,
+            aria_invalid,
+            aria_keyshortcuts,
+            aria_label,
+            aria_labelledby,
+            aria_level,
+            aria_live,
+            aria_modal,
+            aria_multiline,
+            aria_multiselectable,
+            aria_orientation,
+            aria_owns,
+            aria_placeholder,
+            aria_posinset,
+            aria_pressed,
+            aria_readonly,
+            aria_relevant,
+            aria_required,
+            aria_roledescription,
+            aria_rowcount,
+            aria_rowindex,
+            aria_rowindextext,
+            aria_rowspan,
+            aria_selected,
+            aria_setsize,
+            aria_sort,
+            aria_valuemax,
+            aria_valuemin,
+            aria_valuenow,
+            aria_valuetext,
+            accesskey,
+            autocapitalize,
+            contenteditable,
+            dir,
+            draggable,
+            enterkeyhint,
+            exportparts,
+            hidden,
+            inert,
+            inputmode,
+            is,
+            itemid,
+            itemprop,
+            itemref,
+            itemtype,
+            lang,
+            nonce,
+            part,
+            popover,
+            slot,
+            spellcheck,
+            tabindex,
+            translate,
+        );
+    };
+}
+
 macro_rules! write_attr {
     ($this:expr, $f:expr, $attr:ident) => {
-        paste!(attribute::[<$attr _write>](&$this.$attr, $f.f)?);
+        paste!(attribute::[<$attr _write>](&$this.$attr, $f)?);
     };
 }
 
-macro_rules! elements {
-    ($(($name:ident $(,$attr:ident)* $(,)?)),* $(,)*) => {
-        /// An HTML node
+macro_rules! render_methods {
+    () => {
+        /// Render directly to an [`io::Write`](std::io::Write), such as a file or socket
+        ///
+        /// This avoids building the whole document as a `String` first.
+        pub fn render_to(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+            render_to_io(self, w)
+        }
+        /// Render directly to a [`fmt::Write`](std::fmt::Write)
+        pub fn render_to_fmt(&self, w: &mut impl fmt::Write) -> fmt::Result {
+            render_to_fmt(self, w)
+        }
+        /// Render with the given [`RenderOptions`]
+        ///
+        /// The result implements [`Display`](std::fmt::Display).
+        pub fn render_with<'r>(&'r self, options: &'r RenderOptions) -> Rendered<'r> {
+            Rendered::new(self, options)
+        }
+        /// Render without any insignificant whitespace
+        ///
+        /// The result implements [`Display`](std::fmt::Display).
+        pub fn render_compact(&self) -> Rendered<'_> {
+            Rendered::new(self, &COMPACT)
+        }
+    };
+}
+
+/// Format the children and closing tag of an element that is not void
+fn fmt_children(
+    tag: &'static str,
+    kind: ContentKind,
+    children: &[Node],
+    f: &mut IndentFormatter,
+) -> fmt::Result {
+    let omit_end_tag = f.take_omit_end_tag();
+    let outer_content = f.enter_content(tag, kind);
+    fmt_child_nodes(tag, children, f)?;
+    f.exit_content(outer_content);
+    if omit_end_tag {
+        return Ok(());
+    }
+    f.write(format_args!("</{tag}>"))
+}
+
+/// Format the children of an element with the given tag
+fn fmt_child_nodes(tag: &str, children: &[Node], f: &mut IndentFormatter) -> fmt::Result {
+    // The parser drops a newline right after the start tag of these elements
+    if matches!(tag, "pre" | "textarea")
+        && matches!(children.first(), Some(Node::Text(text)) if text.starts_with('\n'))
+    {
+        f.write("\n")?;
+    }
+    let single_line = children.len() <= 1
+        || children.iter().any(|node| matches!(node, Node::Text(_)))
+        || f.options.inline_elements && children.iter().all(Node::is_inline);
+    if single_line {
+        for (i, child) in children.iter().enumerate() {
+            f.set_omit_end_tag(end_tag_optional(child, children.get(i + 1), tag));
+            child.indent_fmt(f)?;
+        }
+    } else {
+        f.writeln("")?;
+        f.indent();
+        for (i, child) in children.iter().enumerate() {
+            f.set_omit_end_tag(end_tag_optional(child, children.get(i + 1), tag));
+            child.indent_fmt(f)?;
+            f.writeln("")?;
+        }
+        f.dedent();
+    }
+    Ok(())
+}
+
+impl<'a> Node<'a> {
+    /// Check if this is an element that is laid out inline
+    fn is_inline(&self) -> bool {
+        matches!(
+            self.tag(),
+            Some(
+                "a" | "abbr"
+                    | "b"
+                    | "bdi"
+                    | "bdo"
+                    | "br"
+                    | "button"
+                    | "cite"
+                    | "code"
+                    | "data"
+                    | "del"
+                    | "dfn"
+                    | "em"
+                    | "i"
+                    | "img"
+                    | "input"
+                    | "ins"
+                    | "kbd"
+                    | "label"
+                    | "mark"
+                    | "output"
+                    | "q"
+                    | "rp"
+                    | "rt"
+                    | "ruby"
+                    | "s"
+                    | "samp"
+                    | "select"
+                    | "small"
+                    | "span"
+                    | "strong"
+                    | "sub"
+                    | "sup"
+                    | "textarea"
+                    | "time"
+                    | "u"
+                    | "var"
+                    | "wbr"
+            )
+        )
+    }
+}
+
+/// Check if the end tag of a node can be omitted,
+/// given the node that follows it and the tag of its parent
+///
+/// See <https://html.spec.whatwg.org/multipage/syntax.html#optional-tags>
+fn end_tag_optional(node: &Node, next: Option<&Node>, parent: &str) -> bool {
+    let Some(tag) = node.tag() else {
+        return false;
+    };
+    let next = match next {
+        Some(next) => match next.tag() {
+            Some(next) => Some(next),
+            // Followed by text or a comment
+            None => return false,
+        },
+        None => None,
+    };
+    match tag {
+        "html" | "head" | "body" | "colgroup" | "caption" => true,
+        "li" => matches!(next, None | Some("li")),
+        "dt" => matches!(next, Some("dt" | "dd")),
+        "dd" => matches!(next, None | Some("dt" | "dd")),
+        "rt" | "rp" => matches!(next, None | Some("rt" | "rp")),
+        "optgroup" => matches!(next, None | Some("optgroup" | "hr")),
+        "option" => matches!(next, None | Some("option" | "optgroup" | "hr")),
+        "thead" => matches!(next, Some("tbody" | "tfoot")),
+        "tbody" => matches!(next, None | Some("tbody" | "tfoot")),
+        "tfoot" => next.is_none(),
+        "tr" => matches!(next, None | Some("tr")),
+        "td" | "th" => matches!(next, None | Some("td" | "th")),
+        "p" => match next {
+            Some(next) => closes_p(next),
+            // Autonomous custom elements, whose names contain a `-`, also keep the end tag
+            None => {
+                !(matches!(
+                    parent,
+                    "a" | "audio" | "del" | "ins" | "map" | "noscript" | "video"
+                ) || parent.contains('-'))
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Set an attribute that is not a field of the element itself
+pub(crate) fn set_other_attribute<'a>(
+    element: &mut impl Element<'a>,
+    name: &str,
+    value: Option<Cow<'a, str>>,
+) {
+    let Err(value) = element.global_mut().set_by_name(name, value) else {
+        return;
+    };
+    if let Some(event) = Event::from_attribute_name(name) {
+        element
+            .events_mut()
+            .insert(event, value.unwrap_or_default());
+    } else if let Some(data_name) = name
+        .strip_prefix("data-")
+        .filter(|n| attribute::is_valid_data_name(n))
+    {
+        let data_name = data_name.to_owned();
+        element
+            .global_mut()
+            .data
+            .insert(data_name, value.unwrap_or_default());
+    } else {
+        element.custom_mut().insert(name.to_owned(), value);
+    }
+}
+
+/// Check if an element with the given tag can follow a `p` element whose end tag is omitted
+pub(crate) fn closes_p(tag: &str) -> bool {
+    matches!(
+        tag,
+        "address"
+            | "article"
+            | "aside"
+            | "blockquote"
+            | "details"
+            | "dialog"
+            | "div"
+            | "dl"
+            | "fieldset"
+            | "figcaption"
+            | "figure"
+            | "footer"
+            | "form"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "hgroup"
+            | "hr"
+            | "main"
+            | "menu"
+            | "nav"
+            | "ol"
+            | "p"
+            | "pre"
+            | "search"
+            | "section"
+            | "table"
+            | "ul"
+    )
+}
+
+macro_rules! content_kind {
+    () => {
+        ContentKind::Normal
+    };
+    (preformatted) => {
+        ContentKind::Preformatted
+    };
+    (raw_text) => {
+        ContentKind::RawText
+    };
+    (escapable_raw_text) => {
+        ContentKind::EscapableRawText
+    };
+}
+
+macro_rules! element_struct {
+    ($name:ident [void] $(,$attr:ident)*) => {
+        paste! {
+            #[derive(Debug, Clone, Default)]
+            #[doc = "A [`<" [<$name:lower>] ">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/" [<$name:lower>] ") element"]
+            #[doc = ""]
+            #[doc = "This is a void element, so it cannot have children."]
+            pub struct $name<'a> {
+                /// The global attributes of this element
+                pub global: GlobalAttributes<'a>,
+                /// The element's events
+                pub events: Events<'a>,
+                $(
+                    #[doc = "The `" $attr "` attribute"]
+                    pub $attr: attribute::[<$attr _t>]<'a>,
+                )*
+                /// Attributes without their own struct
+                pub custom: CustomAttributes<'a>,
+            }
+        }
+
+        impl<'a> $name<'a> {
+            pub(crate) const CONTENT_KIND: std::option::Option<ContentKind> = None;
+            fn fmt_content(&self, _tag: &str, f: &mut IndentFormatter) -> fmt::Result {
+                f.take_omit_end_tag();
+                Ok(())
+            }
+            pub(crate) fn children_if_parent(&self) -> std::option::Option<&[Node<'a>]> {
+                None
+            }
+            pub(crate) fn children_if_parent_mut(&mut self) -> std::option::Option<&mut Vec<Node<'a>>> {
+                None
+            }
+        }
+    };
+    ($name:ident [$($kind:ident)?] $(,$attr:ident)*) => {
+        paste! {
+            #[derive(Debug, Clone, Default)]
+            #[doc = "A [`<" [<$name:lower>] ">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/" [<$name:lower>] ") element"]
+            pub struct $name<'a> {
+                /// The global attributes of this element
+                pub global: GlobalAttributes<'a>,
+                /// The element's events
+                pub events: Events<'a>,
+                $(
+                    #[doc = "The `" $attr "` attribute"]
+                    pub $attr: attribute::[<$attr _t>]<'a>,
+                )*
+                /// Attributes without their own struct
+                pub custom: CustomAttributes<'a>,
+                /// The children of this element
+                pub children: Vec<Node<'a>>,
+            }
+        }
+
+        impl<'a> $name<'a> {
+            pub(crate) const CONTENT_KIND: std::option::Option<ContentKind> = Some(content_kind!($($kind)?));
+            fn fmt_content(&self, tag: &'static str, f: &mut IndentFormatter) -> fmt::Result {
+                fmt_children(tag, content_kind!($($kind)?), &self.children, f)
+            }
+            pub(crate) fn children_if_parent(&self) -> std::option::Option<&[Node<'a>]> {
+                Some(&self.children)
+            }
+            pub(crate) fn children_if_parent_mut(&mut self) -> std::option::Option<&mut Vec<Node<'a>>> {
+                Some(&mut self.children)
+            }
+        }
+
+        impl<'a> ParentElement<'a> for $name<'a> {
+            fn children_mut(&mut self) -> &mut Vec<Node<'a>> {
+                &mut self.children
+            }
+        }
+    };
+}
+
+/// A node of an SVG or MathML element
+pub(crate) trait ForeignNode<'a>: Sized {
+    /// Make an empty element from its tag name, ignoring case
+    fn from_tag(tag: &str) -> Option<Self>;
+    /// Get the mutable children of this element, if it is an element
+    fn children_if_parent_mut(&mut self) -> Option<&mut Vec<Self>>;
+    /// Set an attribute of this element by name
+    ///
+    /// Does nothing if this is not an element.
+    fn set_attribute(&mut self, name: &str, value: Option<Cow<'a, str>>);
+    /// Make a text node
+    fn text(text: Cow<'a, str>) -> Self;
+    /// Make a comment node
+    fn comment(comment: Cow<'a, str>) -> Self;
+    /// Make a raw node
+    fn raw(raw: Cow<'a, str>) -> Self;
+}
+
+/// Format the children and closing tag of an SVG or MathML element
+pub(crate) fn fmt_foreign_children(
+    tag: &str,
+    children: &[impl IndentFormat],
+    single_line: bool,
+    f: &mut IndentFormatter,
+) -> fmt::Result {
+    if single_line {
+        for child in children {
+            child.indent_fmt(f)?;
+        }
+    } else {
+        f.writeln("")?;
+        f.indent();
+        for child in children {
+            child.indent_fmt(f)?;
+            f.writeln("")?;
+        }
+        f.dedent();
+    }
+    f.write(format_args!("</{tag}>"))
+}
+
+/// Define the function that makes an SVG or MathML element
+macro_rules! foreign_element_fn {
+    ($docs:literal, $name:ident, $tag:expr) => {
+        paste!(foreign_element_fn!($docs, $name, [<$name:snake>], $tag););
+    };
+    ($docs:literal, $name:ident, $fn_name:ident, $tag:expr) => {
+        paste! {
+            #[must_use]
+            #[doc = concat!("Make an [`<", $tag, ">`](", $docs, $tag, ") element")]
+            pub fn $fn_name<'a>(elem_data: impl ElementData<element_structs::$name<'a>>) -> element_structs::$name<'a> {
+                let mut elem = std::default::Default::default();
+                elem_data.add_to(&mut elem);
+                elem
+            }
+        }
+    };
+}
+
+/// Get the tag name of an SVG or MathML element
+macro_rules! foreign_tag {
+    ($name:ident) => {
+        paste!(stringify!([<$name:lower>]))
+    };
+    ($name:ident $tag:literal) => {
+        $tag
+    };
+}
+
+/// Define the elements of an XML language that can be embedded in HTML, like SVG
+///
+/// Every element has the attributes of the given group, and elements without
+/// children are rendered as self-closing tags.
+macro_rules! foreign_elements {
+    (
+        $(#[$node_doc:meta])*
+        $node:ident,
+        $structs_doc:literal,
+        $docs:literal,
+        $group_field:ident: $group:ident,
+        $impl_group:ident;
+        $((
+        $name:ident $(= $tag:literal)? $(as $fn_name:ident)?
+        $(,$attr:ident)* $(,)?
+    )),* $(,)?) => {
+        $(#[$node_doc])*
         #[derive(Debug, Clone)]
-        pub enum Node<'a> {
+        pub enum $node<'a> {
             /// A text element
             Text(Cow<'a, str>),
-            /// A comment,
+            /// A comment
             Comment(Cow<'a, str>),
+            /// Trusted markup that is emitted without escaping
+            Raw(Cow<'a, str>),
             $(#[allow(missing_docs)] $name(element_structs::$name<'a>),)*
         }
 
-        impl<'a> fmt::Display for Node<'a> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        impl<'a> $node<'a> {
+            render_methods!();
+        }
+
+        impl<'a> $crate::element::ForeignNode<'a> for $node<'a> {
+            fn from_tag(tag: &str) -> Option<$node<'a>> {
+                $(
+                    if tag.eq_ignore_ascii_case(foreign_tag!($name $($tag)?)) {
+                        return Some($node::$name(std::default::Default::default()));
+                    }
+                )*
+                None
+            }
+            fn children_if_parent_mut(&mut self) -> Option<&mut Vec<$node<'a>>> {
+                match self {
+                    $($node::$name(element) => Some(&mut element.children),)*
+                    $node::Text(_) | $node::Comment(_) | $node::Raw(_) => None,
+                }
+            }
+            fn set_attribute(&mut self, name: &str, value: Option<Cow<'a, str>>) {
                 match self {
-                    $(Node::$name(element) => write!(f, "{element}"),)*
-                    Node::Text(text) => write!(f, "{text}"),
-                    Node::Comment(comment) => write!(f, "<!--{comment}-->"),
+                    $($node::$name(element) => element.set_attribute(name, value),)*
+                    $node::Text(_) | $node::Comment(_) | $node::Raw(_) => {}
                 }
             }
+            fn text(text: Cow<'a, str>) -> Self {
+                $node::Text(text)
+            }
+            fn comment(comment: Cow<'a, str>) -> Self {
+                $node::Comment(comment)
+            }
+            fn raw(raw: Cow<'a, str>) -> Self {
+                $node::Raw(raw)
+            }
         }
 
-        impl<'a> IndentFormat for Node<'a> {
+        impl<'a> fmt::Display for $node<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.indent_fmt(&mut IndentFormatter::new(f))
+            }
+        }
+
+        impl<'a> IndentFormat for $node<'a> {
             fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
                 match self {
-                    $(Node::$name(element) => element.indent_fmt(f),)*
-                    Node::Text(text) => f.write(text),
-                    Node::Comment(comment) => f.write(format_args!("<!--{comment}-->")),
+                    $($node::$name(element) => element.indent_fmt(f),)*
+                    $node::Text(text) => f.write_text(text),
+                    $node::Comment(comment) => f.write(format_args!("<!--{}-->", EscapeComment(comment))),
+                    $node::Raw(markup) => f.write(markup),
                 }
             }
         }
 
         pub mod element_structs {
-            //! Structs that represent HTML elements
+            #![doc = $structs_doc]
 
             use super::*;
             $(
                 paste! {
                     #[derive(Debug, Clone, Default)]
-                    #[doc = "A [`<" [<$name:lower>] ">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/" [<$name:lower>] ") element"]
+                    #[doc = concat!(
+                        "An [`<", foreign_tag!($name $($tag)?),
+                        ">`](", $docs, foreign_tag!($name $($tag)?),
+                        ") element",
+                    )]
                     pub struct $name<'a> {
                         /// The global attributes of this element
                         pub global: GlobalAttributes<'a>,
+                        #[doc = "The [`" $group "`] of this element"]
+                        pub $group_field: $group<'a>,
                         /// The element's events
                         pub events: Events<'a>,
                         $(
                             #[doc = "The `" $attr "` attribute"]
-                            pub $attr: attribute::[<$attr _t>]<'a>,
+                            pub $attr: [<$attr _t>]<'a>,
                         )*
+                        /// Attributes without their own struct
+                        pub custom: CustomAttributes<'a>,
                         /// The children of this element
-                        pub children: Vec<Node<'a>>,
+                        pub children: Vec<$node<'a>>,
+                    }
+                }
+
+                impl<'a> $name<'a> {
+                    /// Get the names and values of the attributes that are set, in the order they are rendered
+                    #[allow(dead_code)]
+                    pub(crate) fn attributes(&self) -> Vec<(Cow<'_, str>, Option<&str>)> {
+                        let mut attrs = Vec::new();
+                        self.global.collect(&mut attrs);
+                        self.$group_field.collect(&mut attrs);
+                        $(push_attr!(self, attrs, $attr);)*
+                        self.custom.collect(&mut attrs);
+                        self.events.collect(&mut attrs);
+                        attrs
                     }
                 }
 
                 impl<'a> IndentFormat for $name<'a> {
                     fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
-                        let tag = paste!(stringify!([<$name:lower>]));
-                        f.write(format_args!("<{tag}"))?;
-                        self.global.indent_fmt(f)?;
-                        $(write_attr!(self, f, $attr);)*
-                        for (event, value) in self.events.iter() {
-                            f.write(format_args!(" {event}=\"{value}\""))?;
-                        }
+                        let tag = foreign_tag!($name $($tag)?);
+                        let attrs = |f: &mut IndentFormatter| {
+                            self.global.indent_fmt(f)?;
+                            self.$group_field.indent_fmt(f)?;
+                            $(paste!([<$attr _write>](&self.$attr, f)?);)*
+                            self.custom.indent_fmt(f)?;
+                            self.events.indent_fmt(f)
+                        };
+                        f.take_omit_end_tag();
                         if self.children.is_empty() {
-                            f.write(format_args!(" />"))?;
-                            return Ok(());
+                            return f.write_self_closing_tag(tag, &attrs);
                         }
-                        f.write(format_args!(">"))?;
-                        let single_line = self.children.len() == 1 || self.children.iter().any(|node| matches!(node, Node::Text(_)));
-                        if single_line {
-                            for child in &self.children {
-                                child.indent_fmt(f)?;
+                        f.write_start_tag(tag, &attrs)?;
+                        let single_line = self.children.len() <= 1
+                            || self.children.iter().any(|node| matches!(node, $node::Text(_)));
+                        fmt_foreign_children(tag, &self.children, single_line, f)
+                    }
+                }
+
+                impl<'a> fmt::Display for $name<'a> {
+                    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        self.indent_fmt(&mut IndentFormatter::new(f))
+                    }
+                }
+
+                impl<'a> $name<'a> {
+                    render_methods!();
+                    /// Set an attribute by name, ignoring case
+                    pub(crate) fn set_attribute(&mut self, name: &str, value: std::option::Option<Cow<'a, str>>) {
+                        $(
+                            if name.eq_ignore_ascii_case(paste!([<$attr:upper _NAME>])) {
+                                self.$attr = paste!([<$attr _parse>](value));
+                                return;
                             }
-                            f.write(format_args!("</{tag}>"))?;
-                            return Ok(());
+                        )*
+                        if let Err(value) = self.$group_field.set_by_name(name, value) {
+                            set_other_attribute(self, name, value);
                         }
-                        f.writeln("")?;
-                        f.indent();
-                        for child in &self.children {
-                            child.indent_fmt(f)?;
-                            f.writeln("")?;
+                    }
+                }
+
+                impl<'a> From<$name<'a>> for $node<'a> {
+                    fn from(element: $name<'a>) -> Self {
+                        $node::$name(element)
+                    }
+                }
+
+                impl<'a, D> ElementData<$name<'a>> for D
+                where
+                    D: Into<$node<'a>>,
+                {
+                    fn add_to(self, element: &mut $name<'a>) {
+                        element.children.push(self.into());
+                    }
+                }
+
+                impl<'a> Element<'a> for $name<'a> {
+                    fn global(&self) -> &GlobalAttributes<'a> {
+                        &self.global
+                    }
+                    fn global_mut(&mut self) -> &mut GlobalAttributes<'a> {
+                        &mut self.global
+                    }
+                    fn events_mut(&mut self) -> &mut Events<'a> {
+                        &mut self.events
+                    }
+                    fn custom_mut(&mut self) -> &mut CustomAttributes<'a> {
+                        &mut self.custom
+                    }
+                }
+
+                impl_all_global_attrs!($name);
+                $impl_group!($name);
+
+                $(
+                    paste! {
+                        impl<'a> [<Has $attr:camel>]<'a> for $name<'a> {
+                            fn [<get_ $attr>](&self) -> [<$attr _ref_t>]<'_> {
+                                [<$attr _take_ref>](&self.$attr)
+                            }
+                            fn [<set_ $attr>](&mut self, val: impl Into<[<$attr _t>]<'a>>) {
+                                self.$attr = val.into();
+                            }
                         }
-                        f.dedent();
-                        f.write(format_args!("</{tag}>"))?;
-                        Ok(())
+                    }
+                )*
+            )*
+        }
+
+        $(foreign_element_fn!($docs, $name, $($fn_name,)? foreign_tag!($name $($tag)?));)*
+    };
+}
+
+macro_rules! elements {
+    ($(($name:ident $([$kind:ident])? $(,$attr:ident)* $(,)?)),* $(,)*) => {
+        /// An HTML node
+        #[derive(Debug, Clone)]
+        #[allow(clippy::large_enum_variant)]
+        pub enum Node<'a> {
+            /// A text element
+            Text(Cow<'a, str>),
+            /// A comment,
+            Comment(Cow<'a, str>),
+            /// Trusted HTML that is emitted without escaping
+            Raw(Cow<'a, str>),
+            /// A custom element
+            Custom(CustomElement<'a>),
+            /// An inline SVG image
+            Svg(crate::svg::element_structs::Svg<'a>),
+            /// A MathML formula
+            Math(crate::mathml::element_structs::Math<'a>),
+            $(#[allow(missing_docs)] $name(element_structs::$name<'a>),)*
+        }
+
+        impl<'a> fmt::Display for Node<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.indent_fmt(&mut IndentFormatter::new(f))
+            }
+        }
+
+        impl<'a> Node<'a> {
+            /// Get the tag name, if this is an element other than a custom element
+            pub(crate) fn tag(&self) -> Option<&'static str> {
+                match self {
+                    $(Node::$name(_) => Some(paste!(stringify!([<$name:lower>]))),)*
+                    Node::Svg(_) => Some("svg"),
+                    Node::Math(_) => Some("math"),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) | Node::Custom(_) => None,
+                }
+            }
+            /// Make an empty element from its tag name
+            pub(crate) fn from_tag(tag: &str) -> Option<Node<'a>> {
+                match tag {
+                    $(paste!(stringify!([<$name:lower>])) => Some(Node::$name(Default::default())),)*
+                    _ => None,
+                }
+            }
+            /// Get the kind of content of this element, or `None` if it cannot have children
+            pub(crate) fn content_kind(&self) -> Option<ContentKind> {
+                match self {
+                    $(Node::$name(_) => element_structs::$name::CONTENT_KIND,)*
+                    Node::Custom(_) => Some(ContentKind::Normal),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) | Node::Svg(_) | Node::Math(_) => None,
+                }
+            }
+            /// Get the global attributes of this element, if it is an element
+            pub(crate) fn global(&self) -> Option<&GlobalAttributes<'a>> {
+                match self {
+                    $(Node::$name(element) => Some(&element.global),)*
+                    Node::Custom(element) => Some(&element.global),
+                    Node::Svg(element) => Some(&element.global),
+                    Node::Math(element) => Some(&element.global),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) => None,
+                }
+            }
+            /// Get the tag name, if this is an element
+            ///
+            /// ```
+            /// use hotman::*;
+            ///
+            /// assert_eq!(Node::from(div(())).tag_name(), Some("div"));
+            /// assert_eq!(Node::from(custom_element("my-card", ())).tag_name(), Some("my-card"));
+            /// assert_eq!(Node::from("text").tag_name(), None);
+            /// ```
+            pub fn tag_name(&self) -> Option<&str> {
+                match self {
+                    Node::Custom(element) => Some(element.name()),
+                    _ => self.tag(),
+                }
+            }
+            /// Get the children of this element, if it can have them
+            ///
+            /// The children of SVG and MathML elements are not [`Node`]s, so they are not included.
+            pub fn children(&self) -> Option<&[Node<'a>]> {
+                match self {
+                    $(Node::$name(element) => element.children_if_parent(),)*
+                    Node::Custom(element) => Some(&element.children),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) | Node::Svg(_) | Node::Math(_) => None,
+                }
+            }
+            /// Get the mutable children of this element, if it can have them
+            ///
+            /// The children of SVG and MathML elements are not [`Node`]s, so they are not included.
+            pub fn children_mut(&mut self) -> Option<&mut Vec<Node<'a>>> {
+                match self {
+                    $(Node::$name(element) => element.children_if_parent_mut(),)*
+                    Node::Custom(element) => Some(&mut element.children),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) | Node::Svg(_) | Node::Math(_) => None,
+                }
+            }
+            /// Iterate over the names and values of the attributes of this element, in the order they are rendered
+            ///
+            /// This includes global attributes, `data-*` attributes, custom attributes, and event handlers.
+            /// Boolean attributes have a value of `None`.
+            ///
+            /// ```
+            /// use hotman::*;
+            ///
+            /// let node = Node::from(input((Type("checkbox"), Checked, Data("id", "7"), On(Change, "save()"))));
+            /// let attrs: Vec<_> = node.attributes().collect();
+            /// assert_eq!(attrs, [
+            ///     ("data-id".into(), Some("7")),
+            ///     ("checked".into(), None),
+            ///     ("type".into(), Some("checkbox")),
+            ///     ("onchange".into(), Some("save()")),
+            /// ]);
+            /// ```
+            pub fn attributes(&self) -> impl Iterator<Item = (Cow<'_, str>, Option<&str>)> {
+                match self {
+                    $(Node::$name(element) => element.attributes(),)*
+                    Node::Custom(element) => element.attributes(),
+                    Node::Svg(element) => element.attributes(),
+                    Node::Math(element) => element.attributes(),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) => Vec::new(),
+                }
+                .into_iter()
+            }
+            /// Set an attribute of this element by name
+            ///
+            /// Does nothing if this is not an element.
+            pub(crate) fn set_attribute(&mut self, name: &str, value: Option<Cow<'a, str>>) {
+                match self {
+                    $(Node::$name(element) => element.set_attribute(name, value),)*
+                    Node::Custom(element) => set_other_attribute(element, name, value),
+                    Node::Svg(element) => element.set_attribute(name, value),
+                    Node::Math(element) => element.set_attribute(name, value),
+                    Node::Text(_) | Node::Comment(_) | Node::Raw(_) => {}
+                }
+            }
+        }
+
+        impl<'a> IndentFormat for Node<'a> {
+            fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+                match self {
+                    $(Node::$name(element) => element.indent_fmt(f),)*
+                    Node::Text(text) => f.write_text(text),
+                    Node::Comment(comment) => f.write(format_args!("<!--{}-->", EscapeComment(comment))),
+                    Node::Raw(html) => f.write(html),
+                    Node::Custom(element) => element.indent_fmt(f),
+                    Node::Svg(element) => element.indent_fmt(f),
+                    Node::Math(element) => element.indent_fmt(f),
+                }
+            }
+        }
+
+        pub mod element_structs {
+            //! Structs that represent HTML elements
+
+            use super::*;
+            $(
+                element_struct!($name [$($kind)?] $(,$attr)*);
+
+                impl<'a> $name<'a> {
+                    /// Get the names and values of the attributes that are set, in the order they are rendered
+                    pub(crate) fn attributes(&self) -> Vec<(Cow<'_, str>, std::option::Option<&str>)> {
+                        let mut attrs = Vec::new();
+                        self.global.collect(&mut attrs);
+                        $(push_attr!(self, attrs, attribute::$attr);)*
+                        self.custom.collect(&mut attrs);
+                        self.events.collect(&mut attrs);
+                        attrs
+                    }
+                }
+
+                impl<'a> IndentFormat for $name<'a> {
+                    fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+                        let tag = paste!(stringify!([<$name:lower>]));
+                        f.write_start_tag(tag, &|f| {
+                            self.global.indent_fmt(f)?;
+                            $(write_attr!(self, f, $attr);)*
+                            self.custom.indent_fmt(f)?;
+                            self.events.indent_fmt(f)
+                        })?;
+                        self.fmt_content(tag, f)
                     }
                 }
 
                 impl<'a> fmt::Display for $name<'a> {
                     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                        self.indent_fmt(&mut IndentFormatter::from(f))
+                        self.indent_fmt(&mut IndentFormatter::new(f))
+                    }
+                }
+
+                impl<'a> $name<'a> {
+                    render_methods!();
+                    /// Set an attribute by name
+                    ///
+                    /// Names without a field go into the global attributes,
+                    /// the events, the `data-*` attributes, or the custom attributes.
+                    pub(crate) fn set_attribute(&mut self, name: &str, value: std::option::Option<Cow<'a, str>>) {
+                        match name {
+                            $(paste!(attribute::[<$attr:upper _NAME>]) => {
+                                self.$attr = paste!(attribute::[<$attr _parse>](value));
+                            })*
+                            _ => set_other_attribute(self, name, value),
+                        }
                     }
                 }
 
@@ -149,20 +1029,26 @@ macro_rules! elements {
                 }
 
                 impl<'a> Element<'a> for $name<'a> {
+                    fn global(&self) -> &GlobalAttributes<'a> {
+                        &self.global
+                    }
+                    fn global_mut(&mut self) -> &mut GlobalAttributes<'a> {
+                        &mut self.global
+                    }
                     fn events_mut(&mut self) -> &mut Events<'a> {
                         &mut self.events
                     }
-                    fn children_mut(&mut self) -> &mut Vec<Node<'a>> {
-                        &mut self.children
+                    fn custom_mut(&mut self) -> &mut CustomAttributes<'a> {
+                        &mut self.custom
                     }
                 }
 
-                impl_global_attrs!($name, id, class, style, title, autofocus, itemscope);
+                impl_all_global_attrs!($name);
 
                 $(
                     paste! {
                         impl<'a> attribute_traits::[<Has $attr:camel>]<'a> for $name<'a> {
-                            fn [<get_ $attr>](&self) -> attribute::[<$attr _ref_t>] {
+                            fn [<get_ $attr>](&self) -> attribute::[<$attr _ref_t>]<'_> {
                                 attribute::[<$attr _take_ref>](&self.$attr)
                             }
                             fn [<set_ $attr>](&mut self, val: impl Into<attribute::[<$attr _t>]<'a>>) {
@@ -187,6 +1073,10 @@ macro_rules! elements {
     };
 }
 
+impl<'a> Node<'a> {
+    render_methods!();
+}
+
 impl<'a> From<String> for Node<'a> {
     fn from(text: String) -> Self {
         Node::Text(text.into())
@@ -206,6 +1096,15 @@ impl<'a> From<&'a String> for Node<'a> {
 }
 
 /// An HTML comment
+///
+/// Text that would end the comment early, like `-->`, has a space inserted to keep it inside the comment.
+///
+/// ```
+/// use hotman::*;
+///
+/// let page = div(Comment("--><script>alert(1)</script><!--"));
+/// assert_eq!(page.to_string(), "<div><!---- ><script>alert(1)</script><!----></div>");
+/// ```
 #[derive(Debug, Clone)]
 pub struct Comment<T>(pub T);
 
@@ -218,6 +1117,194 @@ where
     }
 }
 
+/// Trusted HTML that is emitted without escaping
+///
+/// Only use this with markup you trust, such as the output of a markdown renderer.
+///
+/// ```
+/// use hotman::*;
+///
+/// let article = div(Raw("<p>Rendered <em>elsewhere</em></p>"));
+/// assert_eq!(article.to_string(), "<div><p>Rendered <em>elsewhere</em></p></div>");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Raw<T>(pub T);
+
+impl<'a, T> From<Raw<T>> for Node<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    fn from(raw: Raw<T>) -> Self {
+        Node::Raw(raw.0.into())
+    }
+}
+
+/// Check if a string is a valid
+/// [custom element name](https://html.spec.whatwg.org/multipage/custom-elements.html#valid-custom-element-name)
+///
+/// Custom element names must start with a lowercase ASCII letter, contain a `-`,
+/// and cannot contain uppercase ASCII letters.
+/// A few names used by SVG and MathML, like `font-face`, are reserved.
+pub fn is_valid_custom_element_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.contains('-')
+        && name.chars().all(|c| {
+            matches!(c,
+                '-' | '.' | '0'..='9' | '_' | 'a'..='z'
+                | '\u{B7}'
+                | '\u{C0}'..='\u{D6}'
+                | '\u{D8}'..='\u{F6}'
+                | '\u{F8}'..='\u{37D}'
+                | '\u{37F}'..='\u{1FFF}'
+                | '\u{200C}'..='\u{200D}'
+                | '\u{203F}'..='\u{2040}'
+                | '\u{2070}'..='\u{218F}'
+                | '\u{2C00}'..='\u{2FEF}'
+                | '\u{3001}'..='\u{D7FF}'
+                | '\u{F900}'..='\u{FDCF}'
+                | '\u{FDF0}'..='\u{FFFD}'
+                | '\u{10000}'..='\u{EFFFF}')
+        })
+        && !matches!(
+            name,
+            "annotation-xml"
+                | "color-profile"
+                | "font-face"
+                | "font-face-src"
+                | "font-face-uri"
+                | "font-face-format"
+                | "font-face-name"
+                | "missing-glyph"
+        )
+}
+
+/// A [custom element](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements),
+/// like `<my-button>`
+///
+/// Made with [`custom_element`] or [`CustomElement::new`].
+#[derive(Debug, Clone)]
+pub struct CustomElement<'a> {
+    name: Cow<'a, str>,
+    /// The global attributes of this element
+    pub global: GlobalAttributes<'a>,
+    /// The element's events
+    pub events: Events<'a>,
+    /// The element's other attributes
+    pub custom: CustomAttributes<'a>,
+    /// The children of this element
+    pub children: Vec<Node<'a>>,
+}
+
+impl<'a> CustomElement<'a> {
+    /// Make an empty custom element with the given tag name
+    ///
+    /// Returns `None` if the name is not a valid custom element name.
+    /// See [`is_valid_custom_element_name`].
+    pub fn new(name: impl Into<Cow<'a, str>>) -> Option<Self> {
+        let name = name.into();
+        is_valid_custom_element_name(&name).then(|| CustomElement {
+            name,
+            global: GlobalAttributes::EMPTY,
+            events: Events::NONE,
+            custom: CustomAttributes::NONE,
+            children: Vec::new(),
+        })
+    }
+    /// Get the tag name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Get the names and values of the attributes that are set, in the order they are rendered
+    pub(crate) fn attributes(&self) -> Vec<(Cow<'_, str>, Option<&str>)> {
+        let mut attrs = Vec::new();
+        self.global.collect(&mut attrs);
+        self.custom.collect(&mut attrs);
+        self.events.collect(&mut attrs);
+        attrs
+    }
+    render_methods!();
+}
+
+/// Make a [custom element](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements)
+/// with the given tag name
+///
+/// # Panics
+///
+/// Panics if the name is not a valid custom element name.
+/// See [`is_valid_custom_element_name`].
+///
+/// ```
+/// use hotman::*;
+///
+/// let button = custom_element("my-button", (Attr("variant", "primary"), On(Click, "save()"), "Save"));
+/// assert_eq!(
+///     button.to_string(),
+///     "<my-button variant=\"primary\" onclick=\"save()\">Save</my-button>"
+/// );
+/// ```
+#[must_use]
+pub fn custom_element<'a>(
+    name: impl Into<Cow<'a, str>>,
+    elem_data: impl ElementData<CustomElement<'a>>,
+) -> CustomElement<'a> {
+    let name = name.into();
+    let Some(mut elem) = CustomElement::new(name.clone()) else {
+        panic!("{name:?} is not a valid custom element name");
+    };
+    elem_data.add_to(&mut elem);
+    elem
+}
+
+impl<'a> Element<'a> for CustomElement<'a> {
+    fn global(&self) -> &GlobalAttributes<'a> {
+        &self.global
+    }
+    fn global_mut(&mut self) -> &mut GlobalAttributes<'a> {
+        &mut self.global
+    }
+    fn events_mut(&mut self) -> &mut Events<'a> {
+        &mut self.events
+    }
+    fn custom_mut(&mut self) -> &mut CustomAttributes<'a> {
+        &mut self.custom
+    }
+}
+
+impl_all_global_attrs!(CustomElement);
+
+impl<'a> ParentElement<'a> for CustomElement<'a> {
+    fn children_mut(&mut self) -> &mut Vec<Node<'a>> {
+        &mut self.children
+    }
+}
+
+impl<'a> IndentFormat for CustomElement<'a> {
+    fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+        f.write_start_tag(&self.name, &|f| {
+            self.global.indent_fmt(f)?;
+            self.custom.indent_fmt(f)?;
+            self.events.indent_fmt(f)
+        })?;
+        f.take_omit_end_tag();
+        let outer_content = f.enter_content("", ContentKind::Normal);
+        fmt_child_nodes(&self.name, &self.children, f)?;
+        f.exit_content(outer_content);
+        f.write(format_args!("</{}>", self.name))
+    }
+}
+
+impl<'a> fmt::Display for CustomElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.indent_fmt(&mut IndentFormatter::new(f))
+    }
+}
+
+impl<'a> From<CustomElement<'a>> for Node<'a> {
+    fn from(element: CustomElement<'a>) -> Self {
+        Node::Custom(element)
+    }
+}
+
 elements!(
     (
         A,
@@ -231,8 +1318,9 @@ elements!(
         r#type,
     ),
     (Abbr),
+    (Address),
     (
-        Area,
+        Area[void],
         alt,
         coords,
         download,
@@ -244,14 +1332,16 @@ elements!(
         shape,
         target
     ),
+    (Article),
+    (Aside),
     (Audio, autoplay, controls, r#loop, muted, preload, src),
     (B),
-    (Base, href, target),
-    (Bdi, dir),
-    (Bdo, dir),
+    (Base[void], href, target),
+    (Bdi),
+    (Bdo),
     (Blockquote, cite),
     (Body),
-    (Br, clear),
+    (Br[void], clear),
     (
         Button,
         disabled,
@@ -262,6 +1352,8 @@ elements!(
         formnovalidate,
         formtarget,
         name,
+        popovertarget,
+        popovertargetaction,
         r#type,
         value
     ),
@@ -269,18 +1361,24 @@ elements!(
     (Caption),
     (Cite),
     (Code, r#type),
-    (Col, span),
+    (Col[void], span),
     (Colgroup, span),
+    (Data, value),
+    (Datalist),
     (Dd, r#type),
     (Del, cite, datetime),
-    (Details, open),
+    (Details, name, open),
     (Dfn),
+    (Dialog, open),
     (Div),
     (Dl, r#type),
     (Dt, r#type),
     (Em, r#type),
-    (Embed, height, src, r#type, width),
+    (Embed[void], height, src, r#type, width),
+    (Figcaption),
+    (Figure),
     (Fieldset, disabled, form, name),
+    (Footer),
     (
         Form,
         action,
@@ -298,7 +1396,9 @@ elements!(
     (H5),
     (H6),
     (Head, profile),
-    (Hr, align, color, noshade, size, width),
+    (Header),
+    (Hgroup),
+    (Hr[void], align, color, noshade, size, width),
     (Html, manifest, xmlns),
     (I),
     (
@@ -314,10 +1414,11 @@ elements!(
         width
     ),
     (
-        Img,
+        Img[void],
         alt,
         crossorigin,
         decoding,
+        fetchpriority,
         height,
         importance,
         intrinsicsize,
@@ -331,7 +1432,7 @@ elements!(
         width
     ),
     (
-        Input,
+        Input[void],
         accept,
         alt,
         autocomplete,
@@ -354,6 +1455,8 @@ elements!(
         name,
         pattern,
         placeholder,
+        popovertarget,
+        popovertargetaction,
         readonly,
         required,
         size,
@@ -369,7 +1472,8 @@ elements!(
     (Legend),
     (Li, value),
     (
-        Link,
+        Link[void],
+        r#as,
         href,
         rel,
         media,
@@ -380,52 +1484,70 @@ elements!(
         integrity,
         referrerpolicy
     ),
+    (Main),
     (Map, name),
     (Mark),
     (Menu, r#type, label),
     (Menuitem, checked, command, default, disabled, icon, label, radiogroup, r#type),
-    (Meta, charset, content, http_equiv, name),
+    (Meta[void], charset, content, http_equiv, name),
     (Meter, high, low, max, min, optimum, value),
+    (Nav),
     (Noscript),
-    (Object, data, form, height, name, r#type, usemap, width),
+    (
+        Object,
+        object_data,
+        form,
+        height,
+        name,
+        r#type,
+        usemap,
+        width
+    ),
     (Ol, reversed, start, r#type),
+    (Optgroup, disabled, label),
     (Option, disabled, label, selected, value),
     (Output, r#for, form, name),
     (P),
-    (Param, name, value),
+    (Param[void], name, value),
+    (Picture),
+    (Pre[preformatted]),
     (Progress, max, value),
     (Q, cite),
     (Rp),
     (Rt),
+    (Ruby),
+    (S),
     (Samp),
     (
-        Script,
+        Script[raw_text],
         r#async,
         crossorigin,
         defer,
+        fetchpriority,
         integrity,
         nomodule,
-        nonce,
         referrerpolicy,
         r#type,
         src
     ),
+    (Search),
+    (Section),
     (Select, disabled, form, multiple, name, required, size),
     (Slot, name),
     (Small),
-    (Source, media, sizes, src, srcset, r#type),
+    (Source[void], media, sizes, src, srcset, r#type),
     (Span),
     (Strong),
-    (Style, media, nonce, r#type),
+    (Style[raw_text], media, r#type),
     (Sub),
     (Summary),
     (Sup),
     (Table),
     (Tbody),
     (Td, colspan, headers, rowspan),
-    (Template),
+    (Template, shadowrootmode),
     (
-        Textarea,
+        Textarea[escapable_raw_text],
         autocomplete,
         cols,
         dirname,
@@ -444,9 +1566,10 @@ elements!(
     (Th, colspan, headers, rowspan, scope),
     (Thead),
     (Time, datetime),
-    (Title),
+    (Title[escapable_raw_text]),
     (Tr),
-    (Track, default, kind, label, src, srclang),
+    (Track[void], default, kind, label, src, srclang),
+    (U),
     (Ul),
     (Var),
     (
@@ -463,5 +1586,5 @@ elements!(
         src,
         width
     ),
-    (Wbr),
+    (Wbr[void]),
 );
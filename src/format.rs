@@ -1,22 +1,257 @@
-use std::fmt;
+use std::{borrow::Cow, fmt, io};
 
-pub struct IndentFormatter<'a, 'b> {
-    pub f: &'a mut fmt::Formatter<'b>,
+/// Options for rendering HTML
+///
+/// Use with the `render_with` method of elements, [`Node`](crate::Node)s, and [`Page`](crate::Page)s.
+///
+/// ```
+/// use hotman::*;
+///
+/// let list = ul((li("one"), li("two")));
+///
+/// assert_eq!(list.render_compact().to_string(), "<ul><li>one</li><li>two</li></ul>");
+///
+/// let options = RenderOptions {
+///     omit_optional_end_tags: true,
+///     unquote_attributes: true,
+///     ..RenderOptions::compact()
+/// };
+/// let list = ul((Class("items"), li("one"), li("two")));
+/// assert_eq!(list.render_with(&options).to_string(), "<ul class=items><li>one<li>two</ul>");
+///
+/// let options = RenderOptions {
+///     indent: "\t".into(),
+///     max_width: Some(30),
+///     ..RenderOptions::pretty()
+/// };
+/// let form = form((
+///     input((Type("email"), Name("email"), Placeholder("Email address"))),
+///     p(("Fill in ", em("all"), " of the fields, please")),
+/// ));
+/// assert_eq!(form.render_with(&options).to_string(), "\
+/// <form>
+/// \t<input
+/// \t\tname=\"email\"
+/// \t\tplaceholder=\"Email address\"
+/// \t\ttype=\"email\">
+/// \t<p>Fill in <em>all</em> of
+/// \tthe fields, please</p>
+/// </form>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RenderOptions {
+    /// Whether to add newlines and indentation
+    ///
+    /// If this is `false`, no insignificant whitespace is emitted
+    /// and the other layout options are ignored.
+    pub pretty: bool,
+    /// The string used for one level of indentation
+    pub indent: Cow<'static, str>,
+    /// The line ending to use
+    pub newline: Newline,
+    /// The maximum width of a line
+    ///
+    /// Text is wrapped at spaces to stay within this width when possible.
+    pub max_width: Option<usize>,
+    /// Whether to put each attribute of a start tag on its own line
+    /// if the tag would exceed [`max_width`](RenderOptions::max_width)
+    pub wrap_attributes: bool,
+    /// Whether to keep elements whose children are all inline elements, like `span`, `a`, or `em`, on one line
+    ///
+    /// This avoids adding whitespace between inline elements, which can change how they are laid out.
+    pub inline_elements: bool,
+    /// Whether to leave out end tags that HTML allows to be omitted, like `</li>` and `</p>`
+    ///
+    /// A `</p>` at the end of an element is kept if the element is one that HTML requires it for,
+    /// like `a`, `video`, or a custom element.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let options = RenderOptions {
+    ///     omit_optional_end_tags: true,
+    ///     ..RenderOptions::compact()
+    /// };
+    /// assert_eq!(div(p("a")).render_with(&options).to_string(), "<div><p>a</div>");
+    /// assert_eq!(
+    ///     custom_element("my-el", p("a")).render_with(&options).to_string(),
+    ///     "<my-el><p>a</p></my-el>"
+    /// );
+    /// ```
+    pub omit_optional_end_tags: bool,
+    /// Whether to leave out the quotes around attribute values that don't need them
+    ///
+    /// Self-closing SVG and MathML tags get a space before the `/>`,
+    /// so that the `/` is not read as part of the last value.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let options = RenderOptions {
+    ///     unquote_attributes: true,
+    ///     ..RenderOptions::compact()
+    /// };
+    /// let icon = svg::circle(svg::R("10"));
+    /// assert_eq!(icon.render_with(&options).to_string(), "<circle r=10 />");
+    /// ```
+    pub unquote_attributes: bool,
+}
+
+/// A line ending
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Newline {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl Newline {
+    /// Get the line ending as a string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Newline::Lf => "\n",
+            Newline::CrLf => "\r\n",
+        }
+    }
+}
+
+pub(crate) static PRETTY: RenderOptions = RenderOptions {
+    pretty: true,
+    indent: Cow::Borrowed("    "),
+    newline: Newline::Lf,
+    max_width: None,
+    wrap_attributes: true,
+    inline_elements: false,
+    omit_optional_end_tags: false,
+    unquote_attributes: false,
+};
+
+pub(crate) static COMPACT: RenderOptions = RenderOptions {
+    pretty: false,
+    indent: Cow::Borrowed(""),
+    newline: Newline::Lf,
+    max_width: None,
+    wrap_attributes: false,
+    inline_elements: false,
+    omit_optional_end_tags: false,
+    unquote_attributes: false,
+};
+
+impl RenderOptions {
+    /// The default options, with newlines and four-space indentation
+    pub fn pretty() -> Self {
+        PRETTY.clone()
+    }
+    /// Options that emit no insignificant whitespace
+    pub fn compact() -> Self {
+        COMPACT.clone()
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self::pretty()
+    }
+}
+
+/// Something rendered with [`RenderOptions`]
+///
+/// Implements [`Display`](fmt::Display), so it can be written anywhere or converted to a `String`.
+#[derive(Clone, Copy)]
+pub struct Rendered<'a> {
+    item: &'a dyn IndentFormat,
+    options: &'a RenderOptions,
+}
+
+impl<'a> Rendered<'a> {
+    pub(crate) fn new(item: &'a dyn IndentFormat, options: &'a RenderOptions) -> Self {
+        Rendered { item, options }
+    }
+}
+
+impl<'a> fmt::Display for Rendered<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.item
+            .indent_fmt(&mut IndentFormatter::with_options(f, self.options))
+    }
+}
+
+/// How the content of an element is formatted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    /// Whitespace can be added for layout
+    Normal,
+    /// Whitespace is significant, like in `pre`
+    Preformatted,
+    /// Text is not escaped, like in `script` and `style`
+    RawText,
+    /// Whitespace is significant and text is escaped, like in `textarea` and `title`
+    EscapableRawText,
+}
+
+/// The content that is currently being formatted
+#[derive(Clone, Copy)]
+pub struct ContentState {
+    tag: &'static str,
+    kind: ContentKind,
+    preserve_whitespace: bool,
+}
+
+pub struct IndentFormatter<'a> {
+    f: &'a mut dyn fmt::Write,
+    pub options: &'a RenderOptions,
     indent: usize,
     new_line: bool,
+    column: usize,
+    wrap_attributes: bool,
+    omit_end_tag: bool,
+    content: ContentState,
 }
 
-impl<'a, 'b> From<&'a mut fmt::Formatter<'b>> for IndentFormatter<'a, 'b> {
-    fn from(f: &'a mut fmt::Formatter<'b>) -> Self {
+impl<'a> IndentFormatter<'a> {
+    pub fn new(f: &'a mut dyn fmt::Write) -> Self {
+        Self::with_options(f, &PRETTY)
+    }
+    pub fn with_options(f: &'a mut dyn fmt::Write, options: &'a RenderOptions) -> Self {
         Self {
             f,
+            options,
             indent: 0,
             new_line: false,
+            column: 0,
+            wrap_attributes: false,
+            omit_end_tag: false,
+            content: ContentState {
+                tag: "",
+                kind: ContentKind::Normal,
+                preserve_whitespace: false,
+            },
         }
     }
-}
-
-impl<'a, 'b> IndentFormatter<'a, 'b> {
+    /// Whether whitespace can be added for layout
+    fn pretty(&self) -> bool {
+        self.options.pretty && !self.content.preserve_whitespace
+    }
+    /// Start formatting the children of an element
+    ///
+    /// Returns the outer content, which should be passed to [`exit_content`](Self::exit_content)
+    pub fn enter_content(&mut self, tag: &'static str, kind: ContentKind) -> ContentState {
+        let preserve_whitespace = self.content.preserve_whitespace || kind != ContentKind::Normal;
+        std::mem::replace(
+            &mut self.content,
+            ContentState {
+                tag,
+                kind,
+                preserve_whitespace,
+            },
+        )
+    }
+    /// Finish formatting the children of an element
+    pub fn exit_content(&mut self, outer: ContentState) {
+        self.content = outer;
+    }
     pub fn indent(&mut self) {
         self.indent += 1;
     }
@@ -24,20 +259,306 @@ impl<'a, 'b> IndentFormatter<'a, 'b> {
         self.indent -= 1;
     }
     pub fn write(&mut self, d: impl fmt::Display) -> fmt::Result {
+        use fmt::Write;
+        let mut w = ColumnCounter {
+            f: &mut *self.f,
+            column: &mut self.column,
+        };
         if self.new_line {
             for _ in 0..self.indent {
-                write!(self.f, "    ")?;
+                w.write_str(&self.options.indent)?;
             }
             self.new_line = false;
         }
-        write!(self.f, "{d}")
+        write!(w, "{d}")
     }
     pub fn writeln(&mut self, d: impl fmt::Display) -> fmt::Result {
-        self.new_line = true;
-        writeln!(self.f, "{d}")
+        self.write(d)?;
+        if self.pretty() {
+            self.f.write_str(self.options.newline.as_str())?;
+            self.column = 0;
+            self.new_line = true;
+        }
+        Ok(())
+    }
+    /// Write text, wrapping it at spaces if it would exceed the maximum width
+    ///
+    /// The text is escaped according to the element that contains it.
+    pub fn write_text(&mut self, text: &str) -> fmt::Result {
+        if self.content.kind == ContentKind::RawText {
+            return self.write(EscapeRawText {
+                text,
+                tag: self.content.tag,
+            });
+        }
+        let max_width = match self.options.max_width {
+            Some(max_width) if self.pretty() => max_width,
+            _ => return self.write(EscapeText(text)),
+        };
+        for (i, word) in text.split(' ').enumerate() {
+            if i > 0 {
+                let indent_width = self.indent * self.options.indent.chars().count();
+                let word_width = word.chars().count();
+                if self.column + 1 + word_width > max_width && self.column > indent_width {
+                    self.writeln("")?;
+                } else {
+                    self.write(" ")?;
+                }
+            }
+            self.write(EscapeText(word))?;
+        }
+        Ok(())
+    }
+    /// Write a start tag
+    ///
+    /// The attributes are written by `attrs`, which may be called more than once.
+    pub fn write_start_tag(
+        &mut self,
+        tag: &str,
+        attrs: &dyn Fn(&mut IndentFormatter) -> fmt::Result,
+    ) -> fmt::Result {
+        self.write_tag(tag, attrs, ">")
+    }
+    /// Write an XML-style self-closing tag, like `<path d="M 0 0"/>`
+    ///
+    /// When attributes are unquoted, a space is written before the `/>`
+    /// so that the `/` is not read as part of the last value.
+    pub fn write_self_closing_tag(
+        &mut self,
+        tag: &str,
+        attrs: &dyn Fn(&mut IndentFormatter) -> fmt::Result,
+    ) -> fmt::Result {
+        let end = if self.options.unquote_attributes {
+            " />"
+        } else {
+            "/>"
+        };
+        self.write_tag(tag, attrs, end)
+    }
+    fn write_tag(
+        &mut self,
+        tag: &str,
+        attrs: &dyn Fn(&mut IndentFormatter) -> fmt::Result,
+        end: &str,
+    ) -> fmt::Result {
+        self.write(format_args!("<{tag}"))?;
+        let wrap = match self.options.max_width {
+            Some(max_width) if self.pretty() && self.options.wrap_attributes => {
+                let mut single_line = String::new();
+                attrs(&mut IndentFormatter::with_options(
+                    &mut single_line,
+                    self.options,
+                ))?;
+                self.column + single_line.chars().count() + end.len() > max_width
+            }
+            _ => false,
+        };
+        self.wrap_attributes = wrap;
+        attrs(self)?;
+        self.wrap_attributes = false;
+        self.write(end)
+    }
+    /// Write the whitespace before an attribute
+    fn write_attr_separator(&mut self) -> fmt::Result {
+        if !self.wrap_attributes {
+            return self.write(" ");
+        }
+        self.indent();
+        self.writeln("")?;
+        self.write("")?;
+        self.dedent();
+        Ok(())
+    }
+    /// Write an attribute with a value
+    pub fn write_attr(&mut self, name: impl fmt::Display, value: &str) -> fmt::Result {
+        self.write_attr_separator()?;
+        if self.options.unquote_attributes && can_unquote(value) {
+            self.write(format_args!("{name}={}", EscapeAttr(value)))
+        } else {
+            self.write(format_args!("{name}=\"{}\"", EscapeAttr(value)))
+        }
+    }
+    /// Write a boolean attribute
+    pub fn write_bool_attr(&mut self, name: impl fmt::Display) -> fmt::Result {
+        self.write_attr_separator()?;
+        self.write(name)
+    }
+    /// Mark that the end tag of the next element can be omitted
+    pub fn set_omit_end_tag(&mut self, omit: bool) {
+        self.omit_end_tag = omit && self.options.omit_optional_end_tags;
+    }
+    /// Check if the end tag of the current element should be omitted
+    ///
+    /// Must be called before formatting the element's children
+    pub fn take_omit_end_tag(&mut self) -> bool {
+        std::mem::take(&mut self.omit_end_tag)
+    }
+}
+
+/// Keeps track of the column of the last line written
+struct ColumnCounter<'a> {
+    f: &'a mut dyn fmt::Write,
+    column: &'a mut usize,
+}
+
+impl<'a> fmt::Write for ColumnCounter<'a> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match s.rfind('\n') {
+            Some(i) => *self.column = s[i + 1..].chars().count(),
+            None => *self.column += s.chars().count(),
+        }
+        self.f.write_str(s)
     }
 }
 
+/// Check if an attribute value can be written without quotes
+fn can_unquote(value: &str) -> bool {
+    !value.is_empty()
+        && !value.contains(|c: char| {
+            c.is_ascii_whitespace() || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+        })
+}
+
 pub trait IndentFormat {
     fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result;
 }
+
+/// Render to a [`fmt::Write`]
+pub fn render_to_fmt(item: &impl IndentFormat, w: &mut impl fmt::Write) -> fmt::Result {
+    item.indent_fmt(&mut IndentFormatter::new(w))
+}
+
+/// Render to an [`io::Write`]
+pub fn render_to_io(item: &impl IndentFormat, w: &mut impl io::Write) -> io::Result<()> {
+    let mut adapter = IoAdapter {
+        inner: w,
+        error: None,
+    };
+    render_to_fmt(item, &mut adapter).map_err(|_| {
+        adapter
+            .error
+            .unwrap_or_else(|| io::Error::other("formatting error"))
+    })
+}
+
+/// Lets an [`io::Write`] be used as a [`fmt::Write`], keeping the underlying error
+struct IoAdapter<'a, W> {
+    inner: &'a mut W,
+    error: Option<io::Error>,
+}
+
+impl<'a, W: io::Write> fmt::Write for IoAdapter<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+/// Write `s`, replacing every character for which `escape` returns
+/// an entity with that entity
+fn write_escaped(
+    s: &str,
+    f: &mut impl fmt::Write,
+    escape: impl Fn(char) -> Option<&'static str>,
+) -> fmt::Result {
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        if let Some(entity) = escape(c) {
+            f.write_str(&s[start..i])?;
+            f.write_str(entity)?;
+            start = i + c.len_utf8();
+        }
+    }
+    f.write_str(&s[start..])
+}
+
+/// Displays a string escaped for use as text content
+pub struct EscapeText<'a>(pub &'a str);
+
+impl<'a> fmt::Display for EscapeText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_escaped(self.0, f, |c| match c {
+            '&' => Some("&amp;"),
+            '<' => Some("&lt;"),
+            '>' => Some("&gt;"),
+            _ => None,
+        })
+    }
+}
+
+/// Displays a string escaped for use as a quoted attribute value
+pub struct EscapeAttr<'a>(pub &'a str);
+
+impl<'a> fmt::Display for EscapeAttr<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_escaped(self.0, f, |c| match c {
+            '&' => Some("&amp;"),
+            '<' => Some("&lt;"),
+            '>' => Some("&gt;"),
+            '"' => Some("&quot;"),
+            '\'' => Some("&#39;"),
+            _ => None,
+        })
+    }
+}
+
+/// Displays the text of a raw text element like `script` or `style`
+///
+/// The text is written as-is, except that sequences that would end the element
+/// early (`</script`) or change how its content is parsed (`<!--`) have their `/` or `!`
+/// escaped with a backslash.
+pub struct EscapeRawText<'a> {
+    pub text: &'a str,
+    pub tag: &'a str,
+}
+
+impl<'a> fmt::Display for EscapeRawText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.text.as_bytes();
+        let mut start = 0;
+        for (i, _) in self.text.match_indices('<') {
+            let rest = &bytes[i + 1..];
+            let closes_element = rest.first() == Some(&b'/')
+                && rest.len() > self.tag.len()
+                && rest[1..=self.tag.len()].eq_ignore_ascii_case(self.tag.as_bytes());
+            let opens_comment = rest.starts_with(b"!--");
+            if closes_element || opens_comment {
+                f.write_str(&self.text[start..=i])?;
+                f.write_str("\\")?;
+                start = i + 1;
+            }
+        }
+        f.write_str(&self.text[start..])
+    }
+}
+
+/// Displays the text of a comment
+///
+/// A space is inserted wherever the text would end the comment early (`-->` or `--!>`)
+/// or run into its delimiters (a leading `>` or `->`, or a trailing `<!-`).
+pub struct EscapeComment<'a>(pub &'a str);
+
+impl<'a> fmt::Display for EscapeComment<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = self.0;
+        if text.starts_with('>') || text.starts_with("->") {
+            f.write_str(" ")?;
+        }
+        let mut start = 0;
+        for (i, _) in text.match_indices('>') {
+            let before = &text[..i];
+            if before.ends_with("--") || before.ends_with("--!") {
+                f.write_str(&text[start..i])?;
+                f.write_str(" ")?;
+                start = i;
+            }
+        }
+        f.write_str(&text[start..])?;
+        if text.ends_with("<!-") {
+            f.write_str(" ")?;
+        }
+        Ok(())
+    }
+}
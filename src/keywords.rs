@@ -0,0 +1,281 @@
+//! Typed values for HTML attributes that only allow certain keywords
+//!
+//! Each type can be passed to its attribute in place of a string,
+//! so typos are caught at compile time. Strings still work for values that aren't listed.
+//! A type is only accepted by the attributes it belongs to (see [`ValueOf`](crate::ValueOf)),
+//! so `Method(keywords::InputType::Email)` is a compile error.
+//!
+//! ```
+//! use hotman::*;
+//!
+//! let search = form((
+//!     Method(keywords::Method::Get),
+//!     Target(keywords::Target::Blank),
+//!     input((Type(keywords::InputType::Search), Autocomplete(keywords::Autocomplete::Off))),
+//!     button((Type(keywords::ButtonType::Submit), "Search")),
+//! ));
+//! assert_eq!(
+//!     search.to_string(),
+//!     "\
+//! <form method=\"get\" target=\"_blank\">
+//!     <input autocomplete=\"off\" type=\"search\">
+//!     <button type=\"submit\">Search</button>
+//! </form>"
+//! );
+//! ```
+//!
+//! ```compile_fail
+//! use hotman::*;
+//!
+//! let search = form(Method(keywords::InputType::Email));
+//! ```
+//!
+//! [`InputType`], [`ButtonType`], and [`ScriptType`] are only accepted by the [`Type`](crate::Type)
+//! attribute of their own element.
+//!
+//! ```compile_fail
+//! use hotman::*;
+//!
+//! let loader = script(Type(keywords::InputType::Text));
+//! ```
+
+value_enum! {
+    /// A value of the [`Method`](crate::Method) attribute of `form`
+    /// and the [`Formmethod`](crate::Formmethod) attribute
+    Method for Method, Formmethod {
+        Get = "get",
+        Post = "post",
+        Dialog = "dialog",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Type`](crate::Type) attribute of `input`
+    InputType for Type on Input {
+        Button = "button",
+        Checkbox = "checkbox",
+        Color = "color",
+        Date = "date",
+        DatetimeLocal = "datetime-local",
+        Email = "email",
+        File = "file",
+        Hidden = "hidden",
+        Image = "image",
+        Month = "month",
+        Number = "number",
+        Password = "password",
+        Radio = "radio",
+        Range = "range",
+        Reset = "reset",
+        Search = "search",
+        Submit = "submit",
+        Tel = "tel",
+        Text = "text",
+        Time = "time",
+        Url = "url",
+        Week = "week",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Type`](crate::Type) attribute of `button`
+    ButtonType for Type on Button {
+        Submit = "submit",
+        Reset = "reset",
+        Button = "button",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Type`](crate::Type) attribute of `script`
+    ///
+    /// Other MIME types make the script a data block, which is not run.
+    ScriptType for Type on Script {
+        JavaScript = "text/javascript",
+        Module = "module",
+        ImportMap = "importmap",
+        SpeculationRules = "speculationrules",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Target`](crate::Target) and [`Formtarget`](crate::Formtarget) attributes
+    ///
+    /// Any other value is the name of a browsing context.
+    Target for Target, Formtarget {
+        Blank = "_blank",
+        Self_ = "_self",
+        Parent = "_parent",
+        Top = "_top",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Loading`](crate::Loading) attribute
+    Loading for Loading {
+        Eager = "eager",
+        Lazy = "lazy",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Decoding`](crate::Decoding) attribute
+    Decoding for Decoding {
+        Sync = "sync",
+        Async = "async",
+        Auto = "auto",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Crossorigin`](crate::Crossorigin) attribute
+    CrossOrigin for Crossorigin {
+        Anonymous = "anonymous",
+        UseCredentials = "use-credentials",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Referrerpolicy`](crate::Referrerpolicy) attribute
+    ReferrerPolicy for Referrerpolicy {
+        NoReferrer = "no-referrer",
+        NoReferrerWhenDowngrade = "no-referrer-when-downgrade",
+        Origin = "origin",
+        OriginWhenCrossOrigin = "origin-when-cross-origin",
+        SameOrigin = "same-origin",
+        StrictOrigin = "strict-origin",
+        StrictOriginWhenCrossOrigin = "strict-origin-when-cross-origin",
+        UnsafeUrl = "unsafe-url",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Preload`](crate::Preload) attribute
+    Preload for Preload {
+        None = "none",
+        Metadata = "metadata",
+        Auto = "auto",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Autocomplete`](crate::Autocomplete) attribute
+    ///
+    /// Besides `on` and `off`, these are the autofill field names.
+    /// Tokens like `shipping` or `section-*` can be combined with them in a string.
+    Autocomplete for Autocomplete {
+        On = "on",
+        Off = "off",
+        Name = "name",
+        HonorificPrefix = "honorific-prefix",
+        GivenName = "given-name",
+        AdditionalName = "additional-name",
+        FamilyName = "family-name",
+        HonorificSuffix = "honorific-suffix",
+        Nickname = "nickname",
+        Username = "username",
+        NewPassword = "new-password",
+        CurrentPassword = "current-password",
+        OneTimeCode = "one-time-code",
+        OrganizationTitle = "organization-title",
+        Organization = "organization",
+        StreetAddress = "street-address",
+        AddressLine1 = "address-line1",
+        AddressLine2 = "address-line2",
+        AddressLine3 = "address-line3",
+        AddressLevel1 = "address-level1",
+        AddressLevel2 = "address-level2",
+        AddressLevel3 = "address-level3",
+        AddressLevel4 = "address-level4",
+        Country = "country",
+        CountryName = "country-name",
+        PostalCode = "postal-code",
+        CcName = "cc-name",
+        CcGivenName = "cc-given-name",
+        CcAdditionalName = "cc-additional-name",
+        CcFamilyName = "cc-family-name",
+        CcNumber = "cc-number",
+        CcExp = "cc-exp",
+        CcExpMonth = "cc-exp-month",
+        CcExpYear = "cc-exp-year",
+        CcCsc = "cc-csc",
+        CcType = "cc-type",
+        TransactionCurrency = "transaction-currency",
+        TransactionAmount = "transaction-amount",
+        Language = "language",
+        Bday = "bday",
+        BdayDay = "bday-day",
+        BdayMonth = "bday-month",
+        BdayYear = "bday-year",
+        Sex = "sex",
+        Url = "url",
+        Photo = "photo",
+        Tel = "tel",
+        Email = "email",
+        Impp = "impp",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Wrap`](crate::Wrap) attribute of `textarea`
+    Wrap for Wrap {
+        Soft = "soft",
+        Hard = "hard",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Scope`](crate::Scope) attribute of `th`
+    Scope for Scope {
+        Row = "row",
+        Col = "col",
+        Rowgroup = "rowgroup",
+        Colgroup = "colgroup",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Kind`](crate::Kind) attribute of `track`
+    TrackKind for Kind {
+        Subtitles = "subtitles",
+        Captions = "captions",
+        Descriptions = "descriptions",
+        Chapters = "chapters",
+        Metadata = "metadata",
+    }
+}
+
+value_enum! {
+    /// A value of the [`Rel`](crate::Rel) attribute
+    ///
+    /// Multiple link types can be given in a space-separated string.
+    Rel for Rel {
+        Alternate = "alternate",
+        Author = "author",
+        Bookmark = "bookmark",
+        Canonical = "canonical",
+        DnsPrefetch = "dns-prefetch",
+        External = "external",
+        Help = "help",
+        Icon = "icon",
+        License = "license",
+        Manifest = "manifest",
+        Me = "me",
+        ModulePreload = "modulepreload",
+        Next = "next",
+        Nofollow = "nofollow",
+        Noopener = "noopener",
+        Noreferrer = "noreferrer",
+        Opener = "opener",
+        Pingback = "pingback",
+        Preconnect = "preconnect",
+        Prefetch = "prefetch",
+        Preload = "preload",
+        Prev = "prev",
+        PrivacyPolicy = "privacy-policy",
+        Search = "search",
+        Stylesheet = "stylesheet",
+        Tag = "tag",
+        TermsOfService = "terms-of-service",
+    }
+}
@@ -24,8 +24,29 @@ Html elements are constructed using functions with the same name as the tag.
 
 Examples are [`head`], [`body`], [`div`], and [`p`].
 
+[Custom elements](https://developer.mozilla.org/en-US/docs/Web/API/Web_components/Using_custom_elements)
+like `<my-button>` are made with [`custom_element`].
+
+Inline SVG images are made with [`svg`](fn@svg), and the other SVG elements and attributes are in the [`svg`](mod@svg) module.
+
+Likewise, MathML formulas are made with [`math`], and the other MathML elements and attributes are in the [`mathml`] module.
+
 Elements can be converted to strings with the [`Display`] trait (and by extension, the [`ToString::to_string`] method).
 
+To avoid building a `String`, elements, [`Node`]s, and [`Page`]s can also be streamed
+to an [`io::Write`](std::io::Write) with `render_to` or to a [`fmt::Write`] with `render_to_fmt`.
+
+Output is indented by default.
+Use `render_compact` or `render_with` and [`RenderOptions`] to control the output format.
+
+```rust
+use hotman::*;
+
+let mut response = Vec::new();
+p("Hello!").render_to(&mut response).unwrap();
+assert_eq!(response, b"<p>Hello!</p>");
+```
+
 ## `ElementData`
 
 The [`ElementData`] trait is implemented for any type which adds either attributes or children to an element.
@@ -34,12 +55,49 @@ The [`ElementData`] trait is implemented for any type which adds either attribut
 
 The element functions all take an `ElementData` as their argument, so you can pass tuples for multiple values.
 
+By default, any element can be a child of any element that can have children.
+With the `strict` feature enabled, elements only accept the children that HTML permits,
+so `ul(p("..."))` is a type error. See the `content` module, which is only available with the feature.
+
+**Warning:** the `strict` feature is not additive. It removes the impl that lets any element be a child,
+and Cargo enables a feature for every crate in a build that depends on `hotman`.
+If one crate enables `strict`, other crates in the same build that rely on the default behavior
+may stop compiling. Only enable it in a final binary, never in a library.
+
 ## Attributes
 
 Attributes are represented by structs with the same name as the attribute. They implement [`ElementData`].
 
 Examples are [`Id`], [`Href`], [`Class`], and [`Style`].
 
+Adding most attributes twice overwrites the first value,
+but [`Class`] and [`Style`] values are merged into a [`ClassList`] and [`StyleDeclarations`].
+
+[Global attributes](https://developer.mozilla.org/en-US/docs/Web/HTML/Global_attributes) like [`Lang`], [`Hidden`], and [`Tabindex`] can be added to any element.
+
+`data-*` attributes are added with [`Data`].
+Any other attribute can be added with [`Attr`] or [`BoolAttr`].
+
+Attributes that only allow certain keywords, like [`Method`] and [`Type`], accept typed values from the [`keywords`] module
+as well as strings, so `Method(keywords::Method::Post)` and `Method("post")` are equivalent.
+
+Numbers can be passed directly to numeric attributes like [`Colspan`] and [`Width`],
+and URLs can be percent-encoded and checked with [`Url`].
+Each attribute only accepts strings and the typed values that are meant for it. See [`ValueOf`].
+
+```rust
+use hotman::*;
+
+let cell = td((Colspan(2), Tabindex(-1), input((Type("range"), Min(0), Max(1), Step(0.25)))));
+assert_eq!(
+    cell.to_string(),
+    "<td tabindex=\"-1\" colspan=\"2\"><input max=\"1\" min=\"0\" step=\"0.25\" type=\"range\"></td>"
+);
+```
+
+Accessibility attributes like [`Role`] and [`AriaLabel`] can be added to any element.
+Typed values for them are in the [`aria`] module.
+
 ## Events
 
 Individual event handler attributes do not each have their own struct.
@@ -48,6 +106,76 @@ Instead, they can be added to elements via the [`On`] struct.
 
 `On` implements [`ElementData`] and consists of an [`Event`] and a string representing the handler.
 
+## Escaping
+
+Text is escaped when it is rendered, so it is safe to pass user-supplied strings as children.
+
+Attribute values and event handlers are escaped as well.
+
+To insert trusted markup without escaping, use [`Raw`].
+
+Text inside `script` and `style` elements is not escaped,
+except for sequences like `</script` that would end the element early.
+
+Whitespace inside `pre`, `textarea`, `script`, and `style` elements is
+preserved, so they are never indented.
+
+```rust
+use hotman::*;
+
+let example = div((
+    p("Some code:"),
+    pre(("fn main() {\n    println!(\"hi\");\n}", code("// done"))),
+    script("let s = '</script>';"),
+));
+
+assert_eq!(example.to_string(), "\
+<div>
+    <p>Some code:</p>
+    <pre>fn main() {
+    println!(\"hi\");
+}<code>// done</code></pre>
+    <script>let s = '<\\/script>';</script>
+</div>");
+```
+
+```rust
+use hotman::*;
+
+let cell = td((Title("\"quoted\""), "<script>alert('hi')</script>"));
+
+assert_eq!(
+    cell.to_string(),
+    "<td title=\"&quot;quoted&quot;\">&lt;script&gt;alert('hi')&lt;/script&gt;</td>"
+);
+```
+
+## Parsing
+
+Existing HTML can be parsed into [`Node`]s with [`Node::parse_fragment`] and into a [`Page`] with [`Page::parse`].
+
+Known elements and attributes are parsed into their typed structs and fields.
+
+## Traversal
+
+Once built, a [`Node`]'s tag, attributes, and children can be inspected with
+[`Node::tag_name`], [`Node::attributes`], and [`Node::children`].
+
+[`Node::walk`] and [`Node::walk_mut`] visit every node in a tree with a [`Visitor`] or [`VisitorMut`],
+which are implemented for closures.
+
+Nodes can also be found with CSS selectors using [`Node::select`] and [`Node::select_first`],
+and modified with [`Node::select_mut`] and [`Node::select_first_mut`].
+
+## Validation
+
+[`Node::validate`] and [`Page::validate`] check a tree for problems like invalid nesting,
+duplicate `id`s, and missing required attributes.
+
+[`Node::audit`] and [`Page::audit`] check a tree for common accessibility problems,
+like images without `alt` text and form controls without labels.
+Both return a list of [`Diagnostic`]s, so a test can assert that a rendered page has none.
+
 # Static Example
 
 ```rust
@@ -131,17 +259,44 @@ let page_head = {
 ```
 */
 
+#[macro_use]
+mod value;
+pub mod aria;
+#[macro_use]
 mod attribute;
+mod audit;
+#[cfg(feature = "strict")]
+pub mod content;
+#[macro_use]
 mod element;
 mod format;
+pub mod keywords;
+pub mod mathml;
+mod parse;
+mod select;
+pub mod svg;
+mod url;
+mod validate;
+mod visit;
 
 use std::{
     fmt::{self, Display},
     iter::{FilterMap, FlatMap, Map},
 };
 
+use format::*;
+
 pub use attribute::*;
 pub use element::*;
+pub use format::{Newline, RenderOptions, Rendered};
+pub use mathml::math;
+pub use parse::{ParseError, ParseErrorKind};
+pub use select::is_valid_selector;
+pub use svg::svg;
+pub use url::{Url, UrlError};
+pub use validate::{Diagnostic, DiagnosticKind};
+pub use value::*;
+pub use visit::{Visitor, VisitorMut};
 pub use Event::*;
 
 /// A piece of data that can be added to an element
@@ -152,9 +307,10 @@ pub trait ElementData<E> {
     fn add_to(self, element: &mut E);
 }
 
+#[cfg(not(feature = "strict"))]
 impl<'a, E, D> ElementData<E> for D
 where
-    E: Element<'a>,
+    E: ParentElement<'a>,
     D: Into<Node<'a>>,
 {
     fn add_to(self, elem: &mut E) {
@@ -280,10 +436,20 @@ where
 #[derive(Debug, Clone)]
 pub struct Page<'a>(pub element_structs::Html<'a>);
 
+impl<'a> Page<'a> {
+    render_methods!();
+}
+
+impl<'a> IndentFormat for Page<'a> {
+    fn indent_fmt(&self, f: &mut IndentFormatter) -> fmt::Result {
+        f.writeln("<!DOCTYPE html>")?;
+        self.0.indent_fmt(f)
+    }
+}
+
 impl<'a> Display for Page<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        writeln!(f, "<!DOCTYPE html>")?;
-        write!(f, "{}", self.0)
+        self.indent_fmt(&mut IndentFormatter::new(f))
     }
 }
 
@@ -0,0 +1,179 @@
+//! MathML elements and attributes
+//!
+//! MathML elements are made with the functions in this module, like [`mi`] and [`mfrac`].
+//! A [`math`] element can be added to any HTML element that can have children.
+//!
+//! Attributes that MathML shares with HTML, like [`Width`], [`Height`],
+//! and [`Rowspan`], use the same structs as HTML.
+//! Attributes that are only used by MathML, like [`Display`] and [`Mathvariant`], are in this module.
+//!
+//! Elements without children are rendered as XML-style self-closing tags.
+//!
+//! ```
+//! use hotman::*;
+//!
+//! let formula = p(math((
+//!     mathml::Display("block"),
+//!     mathml::mfrac((mathml::mi("a"), mathml::mn("2"))),
+//!     mathml::mspace(Width("1em")),
+//! )));
+//!
+//! assert_eq!(formula.to_string(), "\
+//! <p><math display=\"block\">
+//!     <mfrac>
+//!         <mi>a</mi>
+//!         <mn>2</mn>
+//!     </mfrac>
+//!     <mspace width=\"1em\"/>
+//! </math></p>");
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use paste::paste;
+
+use crate::{
+    attribute::*,
+    attribute_traits::*,
+    element::{fmt_foreign_children, set_other_attribute},
+    format::*,
+    AttributeValue, Comment, Element, ElementData, Node, Raw,
+};
+
+attributes!(
+    accent,
+    accentunder,
+    alttext,
+    columnspan,
+    depth,
+    display,
+    displaystyle,
+    encoding,
+    fence,
+    largeop,
+    linethickness,
+    lspace,
+    mathbackground,
+    mathcolor,
+    mathsize,
+    mathvariant,
+    maxsize,
+    minsize,
+    movablelimits,
+    rspace,
+    scriptlevel,
+    separator,
+    stretchy,
+    symmetric,
+    voffset,
+);
+
+use self::attribute_traits::*;
+
+attribute_group!(
+    /// Wrapper around the attributes that can be used on any MathML element
+    ///
+    /// Like [`GlobalAttributes`], this keeps the size of the element structs small.
+    MathAttributes(MathAttributesInner),
+    mathml,
+    impl_all_math_attrs,
+    displaystyle,
+    mathbackground,
+    mathcolor,
+    mathsize,
+    mathvariant,
+    scriptlevel,
+);
+
+foreign_elements!(
+    /// A node inside of a MathML element
+    MathNode,
+    "Structs that represent MathML elements",
+    "https://developer.mozilla.org/en-US/docs/Web/MathML/Element/",
+    mathml: MathAttributes,
+    impl_all_math_attrs;
+    (Annotation, encoding),
+    (Math, alttext, display, xmlns),
+    (Merror),
+    (Mfrac, linethickness),
+    (Mi),
+    (Mmultiscripts),
+    (Mn),
+    (
+        Mo,
+        accent,
+        fence,
+        form,
+        largeop,
+        lspace,
+        maxsize,
+        minsize,
+        movablelimits,
+        rspace,
+        separator,
+        stretchy,
+        symmetric,
+    ),
+    (Mover, accent),
+    (Mpadded, depth, height, lspace, voffset, width),
+    (Mphantom),
+    (Mprescripts),
+    (Mroot),
+    (Mrow),
+    (Ms),
+    (Mspace, depth, height, width),
+    (Msqrt),
+    (Mstyle),
+    (Msub),
+    (Msubsup),
+    (Msup),
+    (Mtable),
+    (Mtd, columnspan, rowspan),
+    (Mtext),
+    (Mtr),
+    (Munder, accentunder),
+    (Munderover, accent, accentunder),
+    (Semantics),
+);
+
+impl<'a> From<String> for MathNode<'a> {
+    fn from(text: String) -> Self {
+        MathNode::Text(text.into())
+    }
+}
+
+impl<'a> From<&'a str> for MathNode<'a> {
+    fn from(text: &'a str) -> Self {
+        MathNode::Text(text.into())
+    }
+}
+
+impl<'a> From<&'a String> for MathNode<'a> {
+    fn from(text: &'a String) -> Self {
+        MathNode::Text(text.as_str().into())
+    }
+}
+
+impl<'a, T> From<Comment<T>> for MathNode<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    fn from(comment: Comment<T>) -> Self {
+        MathNode::Comment(comment.0.into())
+    }
+}
+
+impl<'a, T> From<Raw<T>> for MathNode<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    fn from(raw: Raw<T>) -> Self {
+        MathNode::Raw(raw.0.into())
+    }
+}
+
+impl<'a> From<element_structs::Math<'a>> for Node<'a> {
+    fn from(element: element_structs::Math<'a>) -> Self {
+        Node::Math(element)
+    }
+}
@@ -0,0 +1,712 @@
+use std::{borrow::Cow, error::Error, fmt};
+
+use crate::{
+    element::{closes_p, ForeignNode},
+    element_structs::Html,
+    format::ContentKind,
+    is_valid_attribute_name, mathml, svg, CustomElement, Node, Page,
+};
+
+/// An error encountered while parsing HTML
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line where the error occurred, starting at 1
+    pub line: usize,
+    /// The column where the error occurred in characters, starting at 1
+    pub column: usize,
+    /// What went wrong
+    pub kind: ParseErrorKind,
+}
+
+/// The kind of a [`ParseError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A comment is missing its `-->`
+    UnterminatedComment,
+    /// The input ended inside of a tag
+    UnterminatedTag,
+    /// An attribute name contains characters that are not allowed
+    InvalidAttributeName(String),
+    /// An end tag does not match any open element
+    UnexpectedEndTag(String),
+    /// An element that requires an end tag was not closed
+    UnclosedElement(String),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::UnterminatedComment => write!(f, "unterminated comment"),
+            ParseErrorKind::UnterminatedTag => write!(f, "unterminated tag"),
+            ParseErrorKind::InvalidAttributeName(name) => {
+                write!(f, "invalid attribute name `{name}`")
+            }
+            ParseErrorKind::UnexpectedEndTag(tag) => write!(f, "unexpected end tag `</{tag}>`"),
+            ParseErrorKind::UnclosedElement(tag) => write!(f, "unclosed `<{tag}>` element"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}:{}", self.kind, self.line, self.column)
+    }
+}
+
+impl Error for ParseError {}
+
+impl<'a> Node<'a> {
+    /// Parse a fragment of HTML into a list of nodes
+    ///
+    /// Known elements and attributes become their typed structs and fields.
+    /// Other attributes become `data-*`, event, or custom attributes,
+    /// Elements with custom element names become [`Node::Custom`],
+    /// and elements with other unknown tags are kept as [`Node::Raw`].
+    ///
+    /// End tags that HTML allows to be omitted, like those of `li` and `p`, are inferred.
+    /// Whitespace between tags is dropped if it contains a newline.
+    ///
+    /// ```
+    /// use hotman::{attribute_traits::HasClass, *};
+    ///
+    /// let nodes = Node::parse_fragment("<ul class=\"menu\"><li>Home<li>About &amp; Contact</ul>").unwrap();
+    /// let Node::Ul(list) = &nodes[0] else { panic!() };
+    /// assert_eq!(list.get_class(), "menu");
+    /// assert_eq!(list.children.len(), 2);
+    /// assert_eq!(
+    ///     nodes[0].to_string(),
+    ///     "\
+    /// <ul class=\"menu\">
+    ///     <li>Home</li>
+    ///     <li>About &amp; Contact</li>
+    /// </ul>"
+    /// );
+    ///
+    /// let error = Node::parse_fragment("<div>\n  <span>Oops</div>").unwrap_err();
+    /// assert_eq!(error.to_string(), "unclosed `<span>` element at 2:3");
+    /// ```
+    pub fn parse_fragment(html: &'a str) -> Result<Vec<Node<'a>>, ParseError> {
+        Parser {
+            src: html,
+            pos: 0,
+            open: Vec::new(),
+            nodes: Vec::new(),
+        }
+        .parse()
+    }
+}
+
+impl<'a> Page<'a> {
+    /// Parse a full HTML document
+    ///
+    /// The `<!DOCTYPE html>` is optional.
+    /// If the document does not consist of a single `html` element,
+    /// its nodes are wrapped in one.
+    /// Comments before and after the `html` element are moved to the start and end of it.
+    ///
+    /// ```
+    /// use hotman::{attribute_traits::HasLang, *};
+    ///
+    /// let page = Page::parse("<!DOCTYPE html><html lang=\"en\"><body><p>Hi</p></body></html>").unwrap();
+    /// assert_eq!(page.0.get_lang(), "en");
+    ///
+    /// let page = Page::parse("<!-- keep me --><html lang=\"en\"><body></body></html>").unwrap();
+    /// assert_eq!(
+    ///     page.render_compact().to_string(),
+    ///     "<!DOCTYPE html><html lang=\"en\"><!-- keep me --><body></body></html>"
+    /// );
+    /// ```
+    pub fn parse(html: &'a str) -> Result<Page<'a>, ParseError> {
+        let mut nodes = Node::parse_fragment(html)?;
+        let content_count = nodes
+            .iter()
+            .filter(|node| match node {
+                Node::Comment(_) => false,
+                Node::Text(text) => !text.trim().is_empty(),
+                _ => true,
+            })
+            .count();
+        let html_index = nodes.iter().position(|node| matches!(node, Node::Html(_)));
+        if let (1, Some(i)) = (content_count, html_index) {
+            let after = nodes.split_off(i + 1);
+            if let Some(Node::Html(mut html)) = nodes.pop() {
+                let is_comment = |node: &Node| matches!(node, Node::Comment(_));
+                html.children
+                    .splice(0..0, nodes.into_iter().filter(is_comment));
+                html.children.extend(after.into_iter().filter(is_comment));
+                return Ok(Page(html));
+            }
+        }
+        Ok(Page(Html {
+            children: nodes,
+            ..Default::default()
+        }))
+    }
+}
+
+type ParsedAttribute<'a> = (Cow<'a, str>, Option<Cow<'a, str>>);
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    /// The open elements and the positions of their start tags
+    open: Vec<(Node<'a>, usize)>,
+    nodes: Vec<Node<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.src[self.pos..]
+    }
+    fn error(&self, pos: usize, kind: ParseErrorKind) -> ParseError {
+        let before = &self.src[..pos];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            kind,
+        }
+    }
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+    fn skip_whitespace(&mut self) {
+        self.take_while(|c| c.is_ascii_whitespace());
+    }
+    fn parse(mut self) -> Result<Vec<Node<'a>>, ParseError> {
+        while !self.rest().is_empty() {
+            let rest = self.rest();
+            if rest.starts_with("<!--") {
+                let comment = self.comment()?;
+                self.push(Node::Comment(comment));
+            } else if rest.starts_with("</") && starts_with_alpha(&rest[2..]) {
+                self.end_tag()?;
+            } else if rest.starts_with('<') && starts_with_alpha(&rest[1..]) {
+                self.start_tag()?;
+            } else if starts_markup(rest) {
+                if let Some(comment) = self.bogus_comment()? {
+                    self.push(Node::Comment(comment));
+                }
+            } else if let Some(mut text) = self.text() {
+                // A newline right after the start tag of a `pre` is not part of its content
+                if let Some((Node::Pre(pre), _)) = self.open.last() {
+                    if pre.children.is_empty() {
+                        text = strip_leading_newline(text);
+                    }
+                }
+                if !text.is_empty() {
+                    self.push(Node::Text(decode_entities(text)));
+                }
+            }
+        }
+        while let Some((node, start)) = self.open.pop() {
+            let tag = tag_name(&node);
+            if !end_tag_implied(tag) {
+                return Err(self.error(start, ParseErrorKind::UnclosedElement(tag.into())));
+            }
+            self.push(node);
+        }
+        Ok(self.nodes)
+    }
+    /// Add a node to the innermost open element
+    fn push(&mut self, node: Node<'a>) {
+        match (self.open.last_mut()).and_then(|(parent, _)| parent.children_mut()) {
+            Some(children) => children.push(node),
+            None => self.nodes.push(node),
+        }
+    }
+    fn preserves_whitespace(&self) -> bool {
+        (self.open.iter()).any(|(node, _)| node.content_kind() == Some(ContentKind::Preformatted))
+    }
+    /// Parse text up to the next markup
+    ///
+    /// Returns `None` if the text is whitespace that can be dropped
+    fn text(&mut self) -> Option<&'a str> {
+        let rest = self.rest();
+        let len = (rest.char_indices().skip(1))
+            .find(|&(i, _)| starts_markup(&rest[i..]))
+            .map_or(rest.len(), |(i, _)| i);
+        self.pos += len;
+        let text = &rest[..len];
+        if text.trim().is_empty() && text.contains('\n') && !self.preserves_whitespace() {
+            return None;
+        }
+        Some(text)
+    }
+    fn comment(&mut self) -> Result<Cow<'a, str>, ParseError> {
+        let start = self.pos;
+        let rest = &self.rest()[4..];
+        let Some(len) = rest.find("-->") else {
+            return Err(self.error(start, ParseErrorKind::UnterminatedComment));
+        };
+        self.pos += 4 + len + 3;
+        Ok(Cow::Borrowed(&rest[..len]))
+    }
+    /// Parse a doctype, a processing instruction, or some other `<!` or `<?` markup
+    ///
+    /// Returns `None` if the markup should be dropped
+    fn bogus_comment(&mut self) -> Result<Option<Cow<'a, str>>, ParseError> {
+        let start = self.pos;
+        let rest = &self.rest()[2..];
+        let Some(len) = rest.find('>') else {
+            return Err(self.error(start, ParseErrorKind::UnterminatedTag));
+        };
+        self.pos += 2 + len + 1;
+        let is_doctype = rest
+            .get(..7)
+            .is_some_and(|s| s.eq_ignore_ascii_case("doctype"));
+        Ok((!is_doctype && len > 0).then(|| Cow::Borrowed(&rest[..len])))
+    }
+    fn start_tag(&mut self) -> Result<(), ParseError> {
+        let start = self.pos;
+        self.pos += 1;
+        let name = self.take_while(|c| !c.is_ascii_whitespace() && c != '/' && c != '>');
+        let tag = name.to_ascii_lowercase();
+        let foreign = tag == "svg" || tag == "math";
+        let (attributes, self_closing) = self.attributes(start, foreign)?;
+        if foreign {
+            self.close_implied(&tag);
+            let node = if tag == "svg" {
+                let mut svg = svg::element_structs::Svg::default();
+                for (name, value) in attributes {
+                    svg.set_attribute(&name, value);
+                }
+                if !self_closing {
+                    svg.children = self.foreign_children(start, &tag)?;
+                }
+                Node::Svg(svg)
+            } else {
+                let mut math = mathml::element_structs::Math::default();
+                for (name, value) in attributes {
+                    math.set_attribute(&name, value);
+                }
+                if !self_closing {
+                    math.children = self.foreign_children(start, &tag)?;
+                }
+                Node::Math(math)
+            };
+            self.push(node);
+            return Ok(());
+        }
+        let node = Node::from_tag(&tag).or_else(|| {
+            let name = if name.bytes().any(|b| b.is_ascii_uppercase()) {
+                Cow::Owned(tag.clone())
+            } else {
+                Cow::Borrowed(name)
+            };
+            CustomElement::new(name).map(Node::Custom)
+        });
+        let Some(mut node) = node else {
+            let raw = self.unknown_element(start, &tag, self_closing);
+            self.push(Node::Raw(Cow::Borrowed(raw)));
+            return Ok(());
+        };
+        self.close_implied(&tag);
+        for (name, value) in attributes {
+            node.set_attribute(&name, value);
+        }
+        match node.content_kind() {
+            None => self.push(node),
+            Some(kind @ (ContentKind::RawText | ContentKind::EscapableRawText)) => {
+                let mut text = self.raw_text(start, &tag)?;
+                if tag == "textarea" {
+                    text = strip_leading_newline(text);
+                }
+                if !text.is_empty() {
+                    let text = if kind == ContentKind::RawText {
+                        Cow::Borrowed(text)
+                    } else {
+                        decode_entities(text)
+                    };
+                    if let Some(children) = node.children_mut() {
+                        children.push(Node::Text(text));
+                    }
+                }
+                self.push(node);
+            }
+            Some(_) => self.open.push((node, start)),
+        }
+        Ok(())
+    }
+    /// Parse the attributes of a start tag up to and including its `>`
+    ///
+    /// Also returns whether the tag ended with `/>`
+    fn attributes(
+        &mut self,
+        tag_start: usize,
+        keep_case: bool,
+    ) -> Result<(Vec<ParsedAttribute<'a>>, bool), ParseError> {
+        let mut attributes: Vec<ParsedAttribute<'a>> = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(self.error(tag_start, ParseErrorKind::UnterminatedTag));
+            } else if rest.starts_with("/>") {
+                self.pos += 2;
+                return Ok((attributes, true));
+            } else if rest.starts_with('>') {
+                self.pos += 1;
+                return Ok((attributes, false));
+            } else if rest.starts_with('/') {
+                self.pos += 1;
+                continue;
+            }
+            let name_start = self.pos;
+            let mut name =
+                self.take_while(|c| !c.is_ascii_whitespace() && !matches!(c, '/' | '>' | '='));
+            if name.is_empty() {
+                let len = rest.chars().next().map_or(0, char::len_utf8);
+                self.pos += len;
+                name = &rest[..len];
+            }
+            if !is_valid_attribute_name(name) {
+                let kind = ParseErrorKind::InvalidAttributeName(name.into());
+                return Err(self.error(name_start, kind));
+            }
+            self.skip_whitespace();
+            let value = if self.rest().starts_with('=') {
+                self.pos += 1;
+                self.skip_whitespace();
+                let rest = self.rest();
+                let value = match rest.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let Some(len) = rest[1..].find(quote) else {
+                            return Err(self.error(tag_start, ParseErrorKind::UnterminatedTag));
+                        };
+                        self.pos += len + 2;
+                        &rest[1..][..len]
+                    }
+                    _ => self.take_while(|c| !c.is_ascii_whitespace() && c != '>'),
+                };
+                Some(decode_entities(value))
+            } else {
+                None
+            };
+            let name = if keep_case || !name.bytes().any(|b| b.is_ascii_uppercase()) {
+                Cow::Borrowed(name)
+            } else {
+                Cow::Owned(name.to_ascii_lowercase())
+            };
+            // Only the first of duplicate attributes is used
+            if !attributes.iter().any(|(n, _)| *n == name) {
+                attributes.push((name, value));
+            }
+        }
+    }
+    /// Close the open elements whose end tags are implied by a start tag
+    fn close_implied(&mut self, tag: &str) {
+        while let Some(open) = self.open.last().and_then(|(node, _)| node.tag()) {
+            if !closed_by_start_tag(open, tag) {
+                break;
+            }
+            let (node, _) = self.open.pop().unwrap();
+            self.push(node);
+        }
+    }
+    /// Parse the text of an element whose content cannot contain markup,
+    /// along with its end tag
+    fn raw_text(&mut self, start: usize, tag: &str) -> Result<&'a str, ParseError> {
+        let rest = self.rest();
+        let mut from = 0;
+        while let Some(i) = rest[from..].find("</") {
+            let i = from + i;
+            if starts_with_tag(&rest[i + 2..], tag) {
+                let end_tag = &rest[i..];
+                let Some(len) = end_tag.find('>') else {
+                    return Err(self.error(self.pos + i, ParseErrorKind::UnterminatedTag));
+                };
+                self.pos += i + len + 1;
+                return Ok(&rest[..i]);
+            }
+            from = i + 2;
+        }
+        Err(self.error(start, ParseErrorKind::UnclosedElement(tag.into())))
+    }
+    /// Parse an element with an unknown tag, its content, and its end tag as raw markup
+    fn unknown_element(&mut self, start: usize, tag: &str, self_closing: bool) -> &'a str {
+        if !self_closing {
+            let rest = self.rest();
+            let mut depth = 1;
+            let mut from = 0;
+            while let Some(i) = rest[from..].find('<') {
+                let i = from + i;
+                from = i + 1;
+                let after = &rest[i + 1..];
+                if let Some(after) = after.strip_prefix('/') {
+                    if starts_with_tag(after, tag) {
+                        depth -= 1;
+                        if depth == 0 {
+                            if let Some(len) = rest[i..].find('>') {
+                                self.pos += i + len + 1;
+                            }
+                            break;
+                        }
+                    }
+                } else if starts_with_tag(after, tag) {
+                    depth += 1;
+                }
+            }
+        }
+        &self.src[start..self.pos]
+    }
+    /// Parse the children and end tag of an SVG or MathML element
+    ///
+    /// Tags without content can be closed with `/>`, as in XML.
+    fn foreign_children<N: ForeignNode<'a>>(
+        &mut self,
+        start: usize,
+        tag: &str,
+    ) -> Result<Vec<N>, ParseError> {
+        let mut children = Vec::new();
+        loop {
+            let rest = self.rest();
+            if rest.is_empty() {
+                return Err(self.error(start, ParseErrorKind::UnclosedElement(tag.into())));
+            } else if rest.starts_with("<!--") {
+                children.push(N::comment(self.comment()?));
+            } else if let Some(cdata) = rest.strip_prefix("<![CDATA[") {
+                let Some(len) = cdata.find("]]>") else {
+                    return Err(self.error(self.pos, ParseErrorKind::UnterminatedTag));
+                };
+                self.pos += 9 + len + 3;
+                children.push(N::text(Cow::Borrowed(&cdata[..len])));
+            } else if rest.starts_with("</") && starts_with_alpha(&rest[2..]) {
+                self.pos += 2;
+                let name = self.take_while(|c| !c.is_ascii_whitespace() && c != '/' && c != '>');
+                let Some(len) = self.rest().find('>') else {
+                    return Err(self.error(self.pos, ParseErrorKind::UnterminatedTag));
+                };
+                self.pos += len + 1;
+                if !name.eq_ignore_ascii_case(tag) {
+                    return Err(self.error(start, ParseErrorKind::UnclosedElement(tag.into())));
+                }
+                return Ok(children);
+            } else if rest.starts_with('<') && starts_with_alpha(&rest[1..]) {
+                let child_start = self.pos;
+                self.pos += 1;
+                let name = self.take_while(|c| !c.is_ascii_whitespace() && c != '/' && c != '>');
+                let (attributes, self_closing) = self.attributes(child_start, true)?;
+                let Some(mut node) = N::from_tag(name) else {
+                    let tag = name.to_ascii_lowercase();
+                    let raw = self.unknown_element(child_start, &tag, self_closing);
+                    children.push(N::raw(Cow::Borrowed(raw)));
+                    continue;
+                };
+                for (name, value) in attributes {
+                    node.set_attribute(&name, value);
+                }
+                if !self_closing {
+                    if let Some(node_children) = node.children_if_parent_mut() {
+                        *node_children = self.foreign_children(child_start, name)?;
+                    }
+                }
+                children.push(node);
+            } else if starts_markup(rest) {
+                if let Some(comment) = self.bogus_comment()? {
+                    children.push(N::comment(comment));
+                }
+            } else if let Some(text) = self.text() {
+                children.push(N::text(decode_entities(text)));
+            }
+        }
+    }
+    fn end_tag(&mut self) -> Result<(), ParseError> {
+        let start = self.pos;
+        self.pos += 2;
+        let name = self.take_while(|c| !c.is_ascii_whitespace() && c != '/' && c != '>');
+        let tag = name.to_ascii_lowercase();
+        let Some(len) = self.rest().find('>') else {
+            return Err(self.error(start, ParseErrorKind::UnterminatedTag));
+        };
+        self.pos += len + 1;
+        let Some(index) = (self.open.iter()).rposition(|(node, _)| tag_name(node) == tag) else {
+            return Err(self.error(start, ParseErrorKind::UnexpectedEndTag(tag)));
+        };
+        while self.open.len() > index {
+            let (node, node_start) = self.open.pop().unwrap();
+            let node_tag = tag_name(&node);
+            if self.open.len() > index && !end_tag_implied(node_tag) {
+                let kind = ParseErrorKind::UnclosedElement(node_tag.into());
+                return Err(self.error(node_start, kind));
+            }
+            self.push(node);
+        }
+        Ok(())
+    }
+}
+
+/// Get the tag name of an open element
+fn tag_name<'n>(node: &'n Node) -> &'n str {
+    match node {
+        Node::Custom(element) => element.name(),
+        _ => node.tag().unwrap_or_default(),
+    }
+}
+
+fn starts_with_alpha(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+}
+
+/// Check if a `<` at the start of a string begins markup rather than text
+fn starts_markup(s: &str) -> bool {
+    s.strip_prefix('<')
+        .is_some_and(|s| s.starts_with(['!', '?', '/']) || starts_with_alpha(s))
+}
+
+/// Check if a string starts with a tag name, case-insensitively,
+/// followed by the end of the name
+fn starts_with_tag(s: &str, tag: &str) -> bool {
+    s.get(..tag.len())
+        .is_some_and(|name| name.eq_ignore_ascii_case(tag))
+        && s[tag.len()..].starts_with(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+}
+
+fn strip_leading_newline(text: &str) -> &str {
+    (text.strip_prefix("\r\n"))
+        .or_else(|| text.strip_prefix('\n'))
+        .unwrap_or(text)
+}
+
+/// Check if the open element with the first tag is closed by a start tag with the second
+fn closed_by_start_tag(open: &str, tag: &str) -> bool {
+    match open {
+        "p" => closes_p(tag) || matches!(tag, "li" | "dd" | "dt"),
+        "li" => tag == "li",
+        "dt" | "dd" => matches!(tag, "dt" | "dd"),
+        "rt" | "rp" => matches!(tag, "rt" | "rp"),
+        "optgroup" => matches!(tag, "optgroup" | "hr"),
+        "option" => matches!(tag, "option" | "optgroup" | "hr"),
+        "thead" | "tbody" | "tfoot" => matches!(tag, "thead" | "tbody" | "tfoot"),
+        "tr" => matches!(tag, "tr" | "thead" | "tbody" | "tfoot"),
+        "td" | "th" => matches!(tag, "td" | "th" | "tr" | "thead" | "tbody" | "tfoot"),
+        "caption" | "colgroup" => matches!(
+            tag,
+            "caption" | "colgroup" | "thead" | "tbody" | "tfoot" | "tr"
+        ),
+        "head" => tag == "body",
+        _ => false,
+    }
+}
+
+/// Check if an element with the given tag can be closed without an end tag
+fn end_tag_implied(tag: &str) -> bool {
+    matches!(
+        tag,
+        "html"
+            | "head"
+            | "body"
+            | "p"
+            | "li"
+            | "dt"
+            | "dd"
+            | "rt"
+            | "rp"
+            | "optgroup"
+            | "option"
+            | "caption"
+            | "colgroup"
+            | "thead"
+            | "tbody"
+            | "tfoot"
+            | "tr"
+            | "td"
+            | "th"
+    )
+}
+
+/// Replace character references with the characters they represent
+///
+/// References must end with a `;`. Unknown references are left as they are.
+fn decode_entities(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(i) = rest.find('&') {
+        decoded.push_str(&rest[..i]);
+        rest = &rest[i..];
+        let reference = (rest[1..].find(';'))
+            .filter(|&len| len <= 32)
+            .and_then(|len| Some((decode_reference(&rest[1..][..len])?, len + 2)));
+        match reference {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    Cow::Owned(decoded)
+}
+
+/// Decode the name of a character reference between the `&` and the `;`
+fn decode_reference(name: &str) -> Option<char> {
+    if let Some(number) = name.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16),
+            None => number.parse(),
+        }
+        .ok()?;
+        return Some(
+            char::from_u32(code)
+                .filter(|&c| c != '\0')
+                .unwrap_or('\u{FFFD}'),
+        );
+    }
+    Some(match name {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{A0}',
+        "shy" => '\u{AD}',
+        "ensp" => '\u{2002}',
+        "emsp" => '\u{2003}',
+        "thinsp" => '\u{2009}',
+        "zwnj" => '\u{200C}',
+        "zwj" => '\u{200D}',
+        "copy" => '©',
+        "reg" => '®',
+        "trade" => '™',
+        "deg" => '°',
+        "plusmn" => '±',
+        "times" => '×',
+        "divide" => '÷',
+        "micro" => 'µ',
+        "middot" => '·',
+        "bull" => '•',
+        "hellip" => '…',
+        "ndash" => '–',
+        "mdash" => '—',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "sbquo" => '‚',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "bdquo" => '„',
+        "laquo" => '«',
+        "raquo" => '»',
+        "sect" => '§',
+        "para" => '¶',
+        "cent" => '¢',
+        "pound" => '£',
+        "yen" => '¥',
+        "euro" => '€',
+        "larr" => '←',
+        "uarr" => '↑',
+        "rarr" => '→',
+        "darr" => '↓',
+        "harr" => '↔',
+        _ => return None,
+    })
+}
@@ -0,0 +1,575 @@
+use crate::Node;
+
+/// Check if a string is a CSS selector that can be used with [`Node::select`]
+///
+/// Supported selectors are:
+/// - Type selectors like `div` and the universal selector `*`
+/// - Class selectors like `.card` and id selectors like `#main`
+/// - Attribute selectors like `[disabled]`, `[type=password]`, and `[href^="https://"]`,
+///   with the `=`, `~=`, `|=`, `^=`, `$=`, and `*=` operators
+/// - The `:first-child` and `:nth-child()` pseudo-classes, like `:nth-child(2n+1)` or `:nth-child(odd)`
+/// - The descendant (` `), child (`>`), next-sibling (`+`), and subsequent-sibling (`~`) combinators
+/// - Selector lists separated by `,`
+pub fn is_valid_selector(selector: &str) -> bool {
+    SelectorParser::parse(selector).is_ok()
+}
+
+impl<'a> Node<'a> {
+    /// Get every node in this tree that matches a CSS selector, in document order
+    ///
+    /// This node itself can match.
+    /// Only [`Node`]s are searched, so the contents of `svg` and `math` elements never match.
+    ///
+    /// See [`is_valid_selector`] for the supported selectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the selector is not valid.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let node = Node::from(form((
+    ///     input((Type("text"), Name("username"))),
+    ///     input((Type("password"), Name("password"))),
+    ///     p((Class("hint"), "Use at least 12 characters")),
+    /// )));
+    ///
+    /// let passwords = node.select("form input[type=password]");
+    /// assert_eq!(passwords.len(), 1);
+    /// assert_eq!(passwords[0].to_string(), "<input name=\"password\" type=\"password\">");
+    ///
+    /// assert_eq!(node.select("input + input, .hint").len(), 2);
+    /// assert_eq!(node.select("form > :nth-child(odd)").len(), 2);
+    /// ```
+    pub fn select(&self, selector: &str) -> Vec<&Node<'a>> {
+        let selector = parse_selector(selector);
+        let mut nodes = Vec::new();
+        search(
+            &selector,
+            &Context::root(self),
+            &mut Vec::new(),
+            &mut |node, _| {
+                nodes.push(node);
+                true
+            },
+        );
+        nodes
+    }
+    /// Get the first node in this tree that matches a CSS selector
+    ///
+    /// See [`Node::select`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the selector is not valid.
+    pub fn select_first(&self, selector: &str) -> Option<&Node<'a>> {
+        let selector = parse_selector(selector);
+        let mut first = None;
+        search(
+            &selector,
+            &Context::root(self),
+            &mut Vec::new(),
+            &mut |node, _| {
+                first = Some(node);
+                false
+            },
+        );
+        first
+    }
+    /// Get a mutable reference to the first node in this tree that matches a CSS selector
+    ///
+    /// See [`Node::select`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the selector is not valid.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let mut node = Node::from(ul((li("one"), li("two"))));
+    /// if let Some(Node::Li(item)) = node.select_first_mut("li:nth-child(2)") {
+    ///     item.global.class = "active".into();
+    /// }
+    /// assert_eq!(node.to_string(), "\
+    /// <ul>
+    ///     <li>one</li>
+    ///     <li class=\"active\">two</li>
+    /// </ul>");
+    /// ```
+    pub fn select_first_mut(&mut self, selector: &str) -> Option<&mut Node<'a>> {
+        let selector = parse_selector(selector);
+        let mut path = None;
+        search(
+            &selector,
+            &Context::root(self),
+            &mut Vec::new(),
+            &mut |_, p| {
+                path = Some(p.to_vec());
+                false
+            },
+        );
+        self.descendant_mut(&path?)
+    }
+    /// Call a function on every node in this tree that matches a CSS selector, in document order
+    ///
+    /// Matches are found before the function is called,
+    /// so nodes that the function adds are not visited.
+    ///
+    /// See [`Node::select`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the selector is not valid.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let mut node = Node::from(div((a((Href("/home"), "Home")), a((Href("https://example.com"), "Away")))));
+    /// node.select_mut("a[href^=\"https://\"]", |link| {
+    ///     if let Node::A(a) = link {
+    ///         a.target = "_blank".into();
+    ///     }
+    /// });
+    /// assert_eq!(node.select("a[target=_blank]").len(), 1);
+    /// ```
+    pub fn select_mut(&mut self, selector: &str, mut f: impl FnMut(&mut Node<'a>)) {
+        let selector = parse_selector(selector);
+        let mut paths = Vec::new();
+        search(
+            &selector,
+            &Context::root(self),
+            &mut Vec::new(),
+            &mut |_, p| {
+                paths.push(p.to_vec());
+                true
+            },
+        );
+        for path in paths {
+            if let Some(node) = self.descendant_mut(&path) {
+                f(node);
+            }
+        }
+    }
+    /// Get a descendant by the indices of the children that lead to it
+    fn descendant_mut(&mut self, path: &[usize]) -> Option<&mut Node<'a>> {
+        let mut node = self;
+        for &i in path {
+            node = node.children_mut()?.get_mut(i)?;
+        }
+        Some(node)
+    }
+}
+
+fn parse_selector(selector: &str) -> Vec<ComplexSelector> {
+    SelectorParser::parse(selector)
+        .unwrap_or_else(|message| panic!("{selector:?} is not a valid selector: {message}"))
+}
+
+/// A chain of compound selectors separated by combinators
+///
+/// `combinators[i]` is between `compounds[i]` and `compounds[i + 1]`.
+struct ComplexSelector {
+    compounds: Vec<CompoundSelector>,
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Default)]
+struct CompoundSelector {
+    tag: Option<String>,
+    ids: Vec<String>,
+    classes: Vec<String>,
+    attributes: Vec<AttributeSelector>,
+    /// `(a, b)` in `:nth-child(an+b)`
+    nth_child: Vec<(i64, i64)>,
+}
+
+struct AttributeSelector {
+    name: String,
+    value: Option<(AttributeOperator, String)>,
+}
+
+#[derive(Clone, Copy)]
+enum AttributeOperator {
+    Equals,
+    Includes,
+    DashMatch,
+    Prefix,
+    Suffix,
+    Substring,
+}
+
+#[derive(Clone, Copy)]
+enum Combinator {
+    Descendant,
+    Child,
+    NextSibling,
+    SubsequentSibling,
+}
+
+struct SelectorParser<'s> {
+    src: &'s str,
+    pos: usize,
+}
+
+impl<'s> SelectorParser<'s> {
+    fn parse(src: &'s str) -> Result<Vec<ComplexSelector>, String> {
+        let mut parser = SelectorParser { src, pos: 0 };
+        let mut selectors = Vec::new();
+        loop {
+            parser.whitespace();
+            selectors.push(parser.complex()?);
+            parser.whitespace();
+            match parser.next() {
+                None => return Ok(selectors),
+                Some(',') => {}
+                Some(c) => return Err(format!("unexpected {c:?}")),
+            }
+        }
+    }
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+    fn whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+        self.pos > start
+    }
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.next() {
+            Some(c) if c == expected => Ok(()),
+            Some(c) => Err(format!("expected {expected:?} but found {c:?}")),
+            None => Err(format!("expected {expected:?}")),
+        }
+    }
+    fn ident(&mut self) -> Result<&'s str, String> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_alphanumeric() || c == '-' || c == '_' || !c.is_ascii())
+        {
+            self.next();
+        }
+        if self.pos == start {
+            return Err(match self.peek() {
+                Some(c) => format!("expected a name but found {c:?}"),
+                None => "expected a name".into(),
+            });
+        }
+        Ok(&self.src[start..self.pos])
+    }
+    fn complex(&mut self) -> Result<ComplexSelector, String> {
+        let mut selector = ComplexSelector {
+            compounds: vec![self.compound()?],
+            combinators: Vec::new(),
+        };
+        loop {
+            let spaced = self.whitespace();
+            let combinator = match self.peek() {
+                None | Some(',') => return Ok(selector),
+                Some('>') => Combinator::Child,
+                Some('+') => Combinator::NextSibling,
+                Some('~') => Combinator::SubsequentSibling,
+                Some(_) if spaced => Combinator::Descendant,
+                Some(c) => return Err(format!("unexpected {c:?}")),
+            };
+            if !matches!(combinator, Combinator::Descendant) {
+                self.next();
+                self.whitespace();
+            }
+            selector.combinators.push(combinator);
+            selector.compounds.push(self.compound()?);
+        }
+    }
+    fn compound(&mut self) -> Result<CompoundSelector, String> {
+        let mut compound = CompoundSelector::default();
+        let mut empty = true;
+        if self.peek() == Some('*') {
+            self.next();
+            empty = false;
+        } else if self.peek().is_some_and(|c| c.is_alphabetic()) {
+            compound.tag = Some(self.ident()?.to_ascii_lowercase());
+            empty = false;
+        }
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.next();
+                    compound.ids.push(self.ident()?.into());
+                }
+                Some('.') => {
+                    self.next();
+                    compound.classes.push(self.ident()?.into());
+                }
+                Some('[') => {
+                    self.next();
+                    compound.attributes.push(self.attribute()?);
+                }
+                Some(':') => {
+                    self.next();
+                    compound.nth_child.push(self.pseudo_class()?);
+                }
+                _ if empty => {
+                    return Err(match self.peek() {
+                        Some(c) => format!("expected a selector but found {c:?}"),
+                        None => "expected a selector".into(),
+                    })
+                }
+                _ => return Ok(compound),
+            }
+            empty = false;
+        }
+    }
+    fn attribute(&mut self) -> Result<AttributeSelector, String> {
+        self.whitespace();
+        let name = self.ident()?.to_ascii_lowercase();
+        self.whitespace();
+        let operator = match self.next() {
+            Some(']') => return Ok(AttributeSelector { name, value: None }),
+            Some('=') => AttributeOperator::Equals,
+            Some(c) => {
+                let operator = match c {
+                    '~' => AttributeOperator::Includes,
+                    '|' => AttributeOperator::DashMatch,
+                    '^' => AttributeOperator::Prefix,
+                    '$' => AttributeOperator::Suffix,
+                    '*' => AttributeOperator::Substring,
+                    _ => return Err(format!("unexpected {c:?} in attribute selector")),
+                };
+                self.expect('=')?;
+                operator
+            }
+            None => return Err("unterminated attribute selector".into()),
+        };
+        self.whitespace();
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.next();
+                let rest = &self.src[self.pos..];
+                let Some(len) = rest.find(quote) else {
+                    return Err("unterminated string".into());
+                };
+                self.pos += len + 1;
+                rest[..len].into()
+            }
+            _ => self.ident()?.into(),
+        };
+        self.whitespace();
+        self.expect(']')?;
+        Ok(AttributeSelector {
+            name,
+            value: Some((operator, value)),
+        })
+    }
+    fn pseudo_class(&mut self) -> Result<(i64, i64), String> {
+        let name = self.ident()?;
+        if name.eq_ignore_ascii_case("first-child") {
+            return Ok((0, 1));
+        }
+        if !name.eq_ignore_ascii_case("nth-child") {
+            return Err(format!("unsupported pseudo-class :{name}"));
+        }
+        self.expect('(')?;
+        let rest = &self.src[self.pos..];
+        let Some(len) = rest.find(')') else {
+            return Err("unterminated :nth-child".into());
+        };
+        self.pos += len + 1;
+        let arg = &rest[..len];
+        parse_nth(arg).ok_or_else(|| format!("invalid :nth-child argument {:?}", arg.trim()))
+    }
+}
+
+/// Parse the `an+b` argument of `:nth-child`
+fn parse_nth(arg: &str) -> Option<(i64, i64)> {
+    let arg: String = arg.split_ascii_whitespace().collect();
+    let arg = arg.to_ascii_lowercase();
+    match arg.as_str() {
+        "odd" => return Some((2, 1)),
+        "even" => return Some((2, 0)),
+        _ => {}
+    }
+    let Some((a, b)) = arg.split_once('n') else {
+        return Some((0, arg.parse().ok()?));
+    };
+    let a = match a {
+        "" | "+" => 1,
+        "-" => -1,
+        a => a.parse().ok()?,
+    };
+    let b = match b {
+        "" => 0,
+        b if b.starts_with(['+', '-']) => b.trim_start_matches('+').parse().ok()?,
+        _ => return None,
+    };
+    Some((a, b))
+}
+
+/// A node and where it is in the tree
+struct Context<'c, 'n, 'a> {
+    node: &'n Node<'a>,
+    siblings: &'n [Node<'a>],
+    index: usize,
+    parent: Option<&'c Context<'c, 'n, 'a>>,
+}
+
+impl<'c, 'n, 'a> Context<'c, 'n, 'a> {
+    fn root(node: &'n Node<'a>) -> Self {
+        Context {
+            node,
+            siblings: std::slice::from_ref(node),
+            index: 0,
+            parent: None,
+        }
+    }
+    fn sibling(&self, index: usize) -> Self {
+        Context {
+            node: &self.siblings[index],
+            siblings: self.siblings,
+            index,
+            parent: self.parent,
+        }
+    }
+    /// Get the indices of the element siblings before this one, nearest first
+    fn previous_elements(&self) -> impl Iterator<Item = usize> + 'n {
+        let siblings = self.siblings;
+        (0..self.index)
+            .rev()
+            .filter(move |&i| siblings[i].tag_name().is_some())
+    }
+}
+
+/// Visit the nodes that match a selector until `found` returns `false`
+///
+/// Returns `false` if the search was stopped.
+fn search<'n, 'a>(
+    selector: &[ComplexSelector],
+    context: &Context<'_, 'n, 'a>,
+    path: &mut Vec<usize>,
+    found: &mut dyn FnMut(&'n Node<'a>, &[usize]) -> bool,
+) -> bool {
+    if selector
+        .iter()
+        .any(|complex| matches_complex(complex, complex.compounds.len(), context))
+        && !found(context.node, path)
+    {
+        return false;
+    }
+    let children = context.node.children().unwrap_or(&[]);
+    for (index, node) in children.iter().enumerate() {
+        let child = Context {
+            node,
+            siblings: children,
+            index,
+            parent: Some(context),
+        };
+        path.push(index);
+        let keep_going = search(selector, &child, path, found);
+        path.pop();
+        if !keep_going {
+            return false;
+        }
+    }
+    true
+}
+
+/// Check if a node matches the first `len` compounds of a complex selector
+fn matches_complex(selector: &ComplexSelector, len: usize, context: &Context) -> bool {
+    if !matches_compound(&selector.compounds[len - 1], context) {
+        return false;
+    }
+    if len == 1 {
+        return true;
+    }
+    match selector.combinators[len - 2] {
+        Combinator::Descendant => {
+            let mut ancestor = context.parent;
+            while let Some(context) = ancestor {
+                if matches_complex(selector, len - 1, context) {
+                    return true;
+                }
+                ancestor = context.parent;
+            }
+            false
+        }
+        Combinator::Child => context
+            .parent
+            .is_some_and(|parent| matches_complex(selector, len - 1, parent)),
+        Combinator::NextSibling => (context.previous_elements().next())
+            .is_some_and(|i| matches_complex(selector, len - 1, &context.sibling(i))),
+        Combinator::SubsequentSibling => (context.previous_elements())
+            .any(|i| matches_complex(selector, len - 1, &context.sibling(i))),
+    }
+}
+
+fn matches_compound(selector: &CompoundSelector, context: &Context) -> bool {
+    let node = context.node;
+    let (Some(tag), Some(global)) = (node.tag_name(), node.global()) else {
+        return false;
+    };
+    if (selector.tag.as_ref()).is_some_and(|t| !t.eq_ignore_ascii_case(tag)) {
+        return false;
+    }
+    if !selector.ids.iter().all(|id| *global.id == **id) {
+        return false;
+    }
+    if !(selector.classes.iter()).all(|class| global.class.contains(class)) {
+        return false;
+    }
+    if !selector.attributes.is_empty() {
+        let attributes: Vec<_> = node.attributes().collect();
+        let matches = |attr: &AttributeSelector| {
+            attributes.iter().any(|(name, value)| {
+                if !name.eq_ignore_ascii_case(&attr.name) {
+                    return false;
+                }
+                let Some((operator, expected)) = &attr.value else {
+                    return true;
+                };
+                let value = value.unwrap_or("");
+                let expected = expected.as_str();
+                match operator {
+                    AttributeOperator::Equals => value == expected,
+                    AttributeOperator::Includes => {
+                        value.split_ascii_whitespace().any(|v| v == expected)
+                    }
+                    AttributeOperator::DashMatch => {
+                        value == expected
+                            || value
+                                .strip_prefix(expected)
+                                .is_some_and(|rest| rest.starts_with('-'))
+                    }
+                    AttributeOperator::Prefix => {
+                        !expected.is_empty() && value.starts_with(expected)
+                    }
+                    AttributeOperator::Suffix => !expected.is_empty() && value.ends_with(expected),
+                    AttributeOperator::Substring => {
+                        !expected.is_empty() && value.contains(expected)
+                    }
+                }
+            })
+        };
+        if !selector.attributes.iter().all(matches) {
+            return false;
+        }
+    }
+    if !selector.nth_child.is_empty() {
+        let position = context.previous_elements().count() as i64 + 1;
+        let matches = |&(a, b): &(i64, i64)| match a {
+            0 => position == b,
+            a => (position - b) % a == 0 && (position - b) / a >= 0,
+        };
+        if !selector.nth_child.iter().all(matches) {
+            return false;
+        }
+    }
+    true
+}
@@ -0,0 +1,311 @@
+//! SVG elements and attributes
+//!
+//! SVG elements are made with the functions in this module, like [`path`] and [`circle`].
+//! An [`svg`] element can be added to any HTML element that can have children.
+//!
+//! Attributes that SVG shares with HTML, like [`Width`], [`Height`],
+//! and [`Href`], use the same structs as HTML.
+//! Attributes that are only used by SVG, like [`ViewBox`] and [`Fill`], are in this module.
+//!
+//! Elements without children are rendered as XML-style self-closing tags.
+//!
+//! ```
+//! use hotman::*;
+//!
+//! let icon = span(svg((
+//!     svg::ViewBox("0 0 24 24"),
+//!     Width("24"),
+//!     svg::circle((svg::Cx("12"), svg::Cy("12"), svg::R("10"), svg::Fill("gold"))),
+//!     svg::path((svg::D("M 8 14 Q 12 18 16 14"), svg::Stroke("black"))),
+//! )));
+//!
+//! assert_eq!(icon.to_string(), "\
+//! <span><svg viewBox=\"0 0 24 24\" width=\"24\">
+//!     <circle fill=\"gold\" cx=\"12\" cy=\"12\" r=\"10\"/>
+//!     <path stroke=\"black\" d=\"M 8 14 Q 12 18 16 14\"/>
+//! </svg></span>");
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use paste::paste;
+
+use crate::{
+    attribute::*,
+    attribute_traits::*,
+    element::{fmt_foreign_children, set_other_attribute},
+    format::*,
+    AttributeValue, Comment, Element, ElementData, Node, Raw,
+};
+
+attributes!(
+    clip_path = "clip-path",
+    clip_path_units = "clipPathUnits",
+    clip_rule = "clip-rule",
+    cx,
+    cy,
+    d,
+    display,
+    dominant_baseline = "dominant-baseline",
+    dx,
+    dy,
+    fill,
+    fill_opacity = "fill-opacity",
+    fill_rule = "fill-rule",
+    filter,
+    font_family = "font-family",
+    font_size = "font-size",
+    font_style = "font-style",
+    font_weight = "font-weight",
+    fr,
+    fx,
+    fy,
+    gradient_transform = "gradientTransform",
+    gradient_units = "gradientUnits",
+    length_adjust = "lengthAdjust",
+    marker_end = "marker-end",
+    marker_height = "markerHeight",
+    marker_mid = "marker-mid",
+    marker_start = "marker-start",
+    marker_units = "markerUnits",
+    marker_width = "markerWidth",
+    mask,
+    mask_content_units = "maskContentUnits",
+    mask_units = "maskUnits",
+    offset,
+    opacity,
+    orient,
+    path_length = "pathLength",
+    pattern_content_units = "patternContentUnits",
+    pattern_transform = "patternTransform",
+    pattern_units = "patternUnits",
+    points,
+    preserve_aspect_ratio = "preserveAspectRatio",
+    r,
+    ref_x = "refX",
+    ref_y = "refY",
+    rotate,
+    rx,
+    ry,
+    spread_method = "spreadMethod",
+    start_offset = "startOffset",
+    stop_color = "stop-color",
+    stop_opacity = "stop-opacity",
+    stroke,
+    stroke_dasharray = "stroke-dasharray",
+    stroke_dashoffset = "stroke-dashoffset",
+    stroke_linecap = "stroke-linecap",
+    stroke_linejoin = "stroke-linejoin",
+    stroke_miterlimit = "stroke-miterlimit",
+    stroke_opacity = "stroke-opacity",
+    stroke_width = "stroke-width",
+    text_anchor = "text-anchor",
+    text_length = "textLength",
+    transform,
+    vector_effect = "vector-effect",
+    view_box = "viewBox",
+    visibility,
+    x,
+    x1,
+    x2,
+    y,
+    y1,
+    y2,
+);
+
+use self::attribute_traits::*;
+
+attribute_group!(
+    /// Wrapper around the presentation attributes that can be used on any SVG element
+    ///
+    /// Like [`GlobalAttributes`], this keeps the size of the element structs small.
+    PresentationAttributes(PresentationAttributesInner),
+    presentation,
+    impl_all_presentation_attrs,
+    clip_path,
+    clip_rule,
+    display,
+    dominant_baseline,
+    fill,
+    fill_opacity,
+    fill_rule,
+    filter,
+    font_family,
+    font_size,
+    font_style,
+    font_weight,
+    marker_end,
+    marker_mid,
+    marker_start,
+    mask,
+    opacity,
+    stop_color,
+    stop_opacity,
+    stroke,
+    stroke_dasharray,
+    stroke_dashoffset,
+    stroke_linecap,
+    stroke_linejoin,
+    stroke_miterlimit,
+    stroke_opacity,
+    stroke_width,
+    text_anchor,
+    transform,
+    vector_effect,
+    visibility,
+);
+
+foreign_elements!(
+    /// A node inside of an SVG element
+    SvgNode,
+    "Structs that represent SVG elements",
+    "https://developer.mozilla.org/en-US/docs/Web/SVG/Element/",
+    presentation: PresentationAttributes,
+    impl_all_presentation_attrs;
+    (A, href, target),
+    (Circle, cx, cy, path_length, r),
+    (ClipPath = "clipPath", clip_path_units),
+    (Defs),
+    (Desc),
+    (Ellipse, cx, cy, path_length, rx, ry),
+    (G),
+    (Image, height, href, preserve_aspect_ratio, width, x, y),
+    (Line, path_length, x1, x2, y1, y2),
+    (
+        LinearGradient = "linearGradient",
+        gradient_transform,
+        gradient_units,
+        href,
+        spread_method,
+        x1,
+        x2,
+        y1,
+        y2,
+    ),
+    (
+        Marker,
+        marker_height,
+        marker_units,
+        marker_width,
+        orient,
+        preserve_aspect_ratio,
+        ref_x,
+        ref_y,
+        view_box,
+    ),
+    (Mask, height, mask_content_units, mask_units, width, x, y),
+    (Path, d, path_length),
+    (
+        Pattern,
+        height,
+        href,
+        pattern_content_units,
+        pattern_transform,
+        pattern_units,
+        preserve_aspect_ratio,
+        view_box,
+        width,
+        x,
+        y,
+    ),
+    (Polygon, path_length, points),
+    (Polyline, path_length, points),
+    (
+        RadialGradient = "radialGradient",
+        cx,
+        cy,
+        fr,
+        fx,
+        fy,
+        gradient_transform,
+        gradient_units,
+        href,
+        r,
+        spread_method,
+    ),
+    (Rect, height, path_length, rx, ry, width, x, y),
+    (Stop, offset),
+    (
+        Svg,
+        height,
+        preserve_aspect_ratio,
+        view_box,
+        width,
+        x,
+        xmlns,
+        y
+    ),
+    (
+        Symbol,
+        height,
+        preserve_aspect_ratio,
+        ref_x,
+        ref_y,
+        view_box,
+        width,
+        x,
+        y,
+    ),
+    (
+        TextElement = "text" as text,
+        dx,
+        dy,
+        length_adjust,
+        rotate,
+        text_length,
+        x,
+        y,
+    ),
+    (
+        TextPath = "textPath",
+        href,
+        length_adjust,
+        start_offset,
+        text_length
+    ),
+    (Title),
+    (Tspan, dx, dy, length_adjust, rotate, text_length, x, y),
+    (Use as r#use, height, href, width, x, y),
+);
+
+impl<'a> From<String> for SvgNode<'a> {
+    fn from(text: String) -> Self {
+        SvgNode::Text(text.into())
+    }
+}
+
+impl<'a> From<&'a str> for SvgNode<'a> {
+    fn from(text: &'a str) -> Self {
+        SvgNode::Text(text.into())
+    }
+}
+
+impl<'a> From<&'a String> for SvgNode<'a> {
+    fn from(text: &'a String) -> Self {
+        SvgNode::Text(text.as_str().into())
+    }
+}
+
+impl<'a, T> From<Comment<T>> for SvgNode<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    fn from(comment: Comment<T>) -> Self {
+        SvgNode::Comment(comment.0.into())
+    }
+}
+
+impl<'a, T> From<Raw<T>> for SvgNode<'a>
+where
+    T: Into<Cow<'a, str>>,
+{
+    fn from(raw: Raw<T>) -> Self {
+        SvgNode::Raw(raw.0.into())
+    }
+}
+
+impl<'a> From<element_structs::Svg<'a>> for Node<'a> {
+    fn from(element: element_structs::Svg<'a>) -> Self {
+        Node::Svg(element)
+    }
+}
@@ -0,0 +1,249 @@
+use std::{borrow::Cow, error::Error, fmt};
+
+use crate::{AttributeValue, ValueOf};
+
+/// A percent-encoded URL that has been checked for common problems
+///
+/// It can be passed to attributes that take URLs, like [`Href`](crate::Href),
+/// [`Src`](crate::Src), and [`Action`](crate::Action).
+///
+/// Characters that are not allowed in URLs, like spaces and non-ASCII characters,
+/// are percent-encoded. Existing percent-encoded sequences are left alone.
+///
+/// `javascript:` and `vbscript:` URLs are rejected.
+/// `data:` URLs are only allowed for plain text, images other than SVG, audio, video, and fonts,
+/// since other media types like `text/html` can run scripts.
+///
+/// ```
+/// use hotman::*;
+///
+/// let url = Url::parse("/files/my résumé.pdf?v=2#page 1").unwrap();
+/// assert_eq!(url.as_str(), "/files/my%20r%C3%A9sum%C3%A9.pdf?v=2#page%201");
+///
+/// let link = a((Href(url), "Résumé"));
+/// assert_eq!(
+///     link.to_string(),
+///     "<a href=\"/files/my%20r%C3%A9sum%C3%A9.pdf?v=2#page%201\">Résumé</a>"
+/// );
+///
+/// assert_eq!(
+///     Url::parse("javascript:alert(1)"),
+///     Err(UrlError::UnsafeScheme("javascript".into()))
+/// );
+/// assert_eq!(
+///     Url::parse("https://example.com:99999/"),
+///     Err(UrlError::InvalidPort("99999".into()))
+/// );
+/// assert_eq!(
+///     Url::parse("data:text/html,<script>alert(1)</script>"),
+///     Err(UrlError::UnsafeScheme("data".into()))
+/// );
+/// assert!(Url::parse("data:image/png;base64,iVBORw0KGgo=").is_ok());
+/// assert_eq!(
+///     Url::parse("http://[::1"),
+///     Err(UrlError::InvalidHost("[::1".into()))
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Url<'a>(Cow<'a, str>);
+
+/// An error encountered while parsing a [`Url`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UrlError {
+    /// The scheme runs scripts, like `javascript:`,
+    /// or it is a `data:` URL with a media type that can run scripts, like `text/html`
+    UnsafeScheme(String),
+    /// A URL with a scheme like `https:` has no host
+    MissingHost,
+    /// The host contains characters that are not allowed
+    InvalidHost(String),
+    /// The port is not a number from 0 to 65535
+    InvalidPort(String),
+}
+
+impl fmt::Display for UrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UrlError::UnsafeScheme(scheme) => write!(f, "unsafe URL scheme `{scheme}`"),
+            UrlError::MissingHost => write!(f, "URL is missing a host"),
+            UrlError::InvalidHost(host) => write!(f, "invalid URL host `{host}`"),
+            UrlError::InvalidPort(port) => write!(f, "invalid URL port `{port}`"),
+        }
+    }
+}
+
+impl Error for UrlError {}
+
+/// Schemes that run scripts when a URL is followed
+const UNSAFE_SCHEMES: &[&str] = &["javascript", "vbscript"];
+
+/// Schemes that must have a host
+const SPECIAL_SCHEMES: &[&str] = &["http", "https", "ws", "wss", "ftp"];
+
+impl<'a> Url<'a> {
+    /// Percent-encode and check a URL
+    ///
+    /// Leading and trailing whitespace is removed, as browsers would do.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the URL can run scripts, or if its host or port is not valid.
+    pub fn parse(url: impl Into<Cow<'a, str>>) -> Result<Self, UrlError> {
+        let url = url.into();
+        let trimmed = url.trim_matches(|c: char| c.is_ascii_whitespace() || c.is_ascii_control());
+        let scheme = url_scheme(trimmed);
+        let rest = scheme.map_or(trimmed, |scheme| &trimmed[scheme.len() + 1..]);
+        if let Some(scheme) = scheme {
+            let unsafe_scheme = UNSAFE_SCHEMES
+                .iter()
+                .any(|s| s.eq_ignore_ascii_case(scheme))
+                || scheme.eq_ignore_ascii_case("data") && !is_safe_data(rest);
+            if unsafe_scheme {
+                return Err(UrlError::UnsafeScheme(scheme.into()));
+            }
+        }
+        let mut encoded = String::with_capacity(trimmed.len());
+        if let Some(scheme) = scheme {
+            encoded.push_str(scheme);
+            encoded.push(':');
+        }
+        let path = if let Some(rest) = rest.strip_prefix("//") {
+            let end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+            let (authority, path) = rest.split_at(end);
+            check_authority(authority, scheme)?;
+            encoded.push_str("//");
+            percent_encode(authority, &mut encoded);
+            path
+        } else {
+            if scheme.is_some_and(is_special) {
+                return Err(UrlError::MissingHost);
+            }
+            rest
+        };
+        percent_encode(path, &mut encoded);
+        Ok(Url(if encoded == *url {
+            url
+        } else {
+            Cow::Owned(encoded)
+        }))
+    }
+    /// Get the encoded URL as a string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Check if the media type of a `data:` URL can't run scripts
+fn is_safe_data(data: &str) -> bool {
+    let media_type = data.split([',', ';']).next().unwrap_or_default();
+    let media_type = media_type.trim().to_ascii_lowercase();
+    media_type.is_empty()
+        || media_type == "text/plain"
+        || media_type.starts_with("image/") && media_type != "image/svg+xml"
+        || ["audio/", "video/", "font/"]
+            .iter()
+            .any(|prefix| media_type.starts_with(prefix))
+}
+
+/// Get the scheme of a URL, if it has one
+fn url_scheme(url: &str) -> Option<&str> {
+    let (scheme, _) = url.split_once(':')?;
+    let mut chars = scheme.chars();
+    let valid = chars.next()?.is_ascii_alphabetic()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some(scheme)
+}
+
+/// Check if a scheme must have a host
+fn is_special(scheme: &str) -> bool {
+    SPECIAL_SCHEMES
+        .iter()
+        .any(|s| s.eq_ignore_ascii_case(scheme))
+}
+
+fn check_authority(authority: &str, scheme: Option<&str>) -> Result<(), UrlError> {
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    let (host, port) = if let Some(literal) = host_port.strip_prefix('[') {
+        let Some(end) = literal.find(']') else {
+            return Err(UrlError::InvalidHost(host_port.into()));
+        };
+        let (host, port) = host_port.split_at(end + 2);
+        let valid = host[1..host.len() - 1]
+            .chars()
+            .all(|c| c.is_ascii_hexdigit() || matches!(c, ':' | '.'));
+        if !valid {
+            return Err(UrlError::InvalidHost(host.into()));
+        }
+        match port {
+            "" => (host, None),
+            _ => match port.strip_prefix(':') {
+                Some(port) => (host, Some(port)),
+                None => return Err(UrlError::InvalidHost(host_port.into())),
+            },
+        }
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+    let forbidden = |c: char| {
+        c.is_ascii_whitespace()
+            || c.is_ascii_control()
+            || matches!(
+                c,
+                '#' | '%' | '/' | ':' | '<' | '>' | '?' | '@' | '[' | '\\' | ']' | '^' | '|'
+            )
+    };
+    if !host.starts_with('[') && host.contains(forbidden) {
+        return Err(UrlError::InvalidHost(host.into()));
+    }
+    if host.is_empty() && scheme.is_some_and(is_special) {
+        return Err(UrlError::MissingHost);
+    }
+    if let Some(port) = port {
+        let valid = port.bytes().all(|b| b.is_ascii_digit()) && port.parse::<u16>().is_ok();
+        if !port.is_empty() && !valid {
+            return Err(UrlError::InvalidPort(port.into()));
+        }
+    }
+    Ok(())
+}
+
+/// Percent-encode the characters that are not allowed in a URL
+fn percent_encode(s: &str, encoded: &mut String) {
+    let bytes = s.as_bytes();
+    for (i, c) in s.char_indices() {
+        let allowed = c.is_ascii_alphanumeric()
+            || "-._~!$&'()*+,;=:@/?#[]".contains(c)
+            || c == '%'
+                && bytes
+                    .get(i + 1..i + 3)
+                    .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+        if allowed {
+            encoded.push(c);
+        } else {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                encoded.push_str(&format!("%{byte:02X}"));
+            }
+        }
+    }
+}
+
+impl<'a> AttributeValue<'a> for Url<'a> {
+    fn into_attribute_value(self) -> Cow<'a, str> {
+        self.0
+    }
+}
+
+impl<E> ValueOf<crate::Href, E> for Url<'_> {}
+impl<E> ValueOf<crate::Src, E> for Url<'_> {}
+impl<E> ValueOf<crate::Action, E> for Url<'_> {}
+
+impl<'a> fmt::Display for Url<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,381 @@
+use std::{collections::HashSet, error::Error, fmt};
+
+use crate::{element::closes_p, keywords::*, AttributeValue, GlobalAttributes, Node, Page};
+
+/// A problem found by [`Node::validate`], [`Page::validate`], [`Node::audit`], or [`Page::audit`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The path from the validated node to the offending node
+    ///
+    /// Each index is the position of a node among its parent's children.
+    /// An empty path is the validated node itself.
+    pub path: Vec<usize>,
+    /// The kind of problem
+    pub kind: DiagnosticKind,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at /", self.kind)?;
+        for (i, index) in self.path.iter().enumerate() {
+            if i > 0 {
+                write!(f, "/")?;
+            }
+            write!(f, "{index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for Diagnostic {}
+
+/// A kind of [`Diagnostic`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiagnosticKind {
+    /// An element is inside of an element that cannot contain it
+    InvalidNesting {
+        /// The tag of the containing element
+        ancestor: &'static str,
+        /// The tag of the element
+        element: &'static str,
+    },
+    /// An element has the same `id` as an earlier element
+    DuplicateId(String),
+    /// An element is missing an attribute that it requires
+    MissingAttribute {
+        /// The tag of the element
+        element: &'static str,
+        /// The name of the attribute
+        attribute: &'static str,
+    },
+    /// An attribute has a value that is not one of its keywords
+    InvalidAttributeValue {
+        /// The name of the attribute
+        attribute: &'static str,
+        /// The value of the attribute
+        value: String,
+    },
+    /// A form control has no label
+    UnlabeledControl(&'static str),
+    /// A heading is more than one level below the heading before it
+    SkippedHeadingLevel {
+        /// The level of the heading before
+        previous: u8,
+        /// The level of the heading
+        level: u8,
+    },
+    /// A link or button has no text for assistive technology to announce
+    MissingAccessibleText(&'static str),
+    /// An element has a `tabindex` greater than 0, which changes the tab order
+    PositiveTabindex(String),
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticKind::InvalidNesting { ancestor, element } => {
+                write!(f, "<{element}> cannot be inside of <{ancestor}>")
+            }
+            DiagnosticKind::DuplicateId(id) => write!(f, "duplicate id {id:?}"),
+            DiagnosticKind::MissingAttribute { element, attribute } => {
+                write!(f, "<{element}> is missing the {attribute} attribute")
+            }
+            DiagnosticKind::InvalidAttributeValue { attribute, value } => {
+                write!(f, "invalid value {value:?} for the {attribute} attribute")
+            }
+            DiagnosticKind::UnlabeledControl(element) => write!(f, "<{element}> has no label"),
+            DiagnosticKind::SkippedHeadingLevel { previous, level } => {
+                write!(f, "<h{level}> skips a level after <h{previous}>")
+            }
+            DiagnosticKind::MissingAccessibleText(element) => {
+                write!(f, "<{element}> has no accessible text")
+            }
+            DiagnosticKind::PositiveTabindex(tabindex) => {
+                write!(f, "tabindex {tabindex} is greater than 0")
+            }
+        }
+    }
+}
+
+impl<'a> Node<'a> {
+    /// Check this node and its descendants for violations of the HTML spec
+    ///
+    /// This checks for:
+    /// - Elements nested in elements that cannot contain them, like a `p` in a `p` or an `a` in an `a`
+    /// - Duplicate `id`s
+    /// - Missing required attributes, like `alt` on `img`,
+    ///   and `script` elements with neither a `src` nor inline content
+    /// - Invalid values of attributes that only allow certain keywords, like `dir` and `method`
+    ///
+    /// Void elements cannot have children, so they do not need to be checked.
+    /// The contents of `svg` and `math` elements are not checked.
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let node: Node = div((a(("Home ", a("page"))), img(Src("/cat.png")))).into();
+    /// let diagnostics = node.validate();
+    ///
+    /// assert_eq!(diagnostics.len(), 2);
+    /// assert_eq!(diagnostics[0].to_string(), "<a> cannot be inside of <a> at /0/1");
+    /// assert_eq!(diagnostics[1].to_string(), "<img> is missing the alt attribute at /1");
+    /// ```
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+        validator.node(self);
+        validator.diagnostics
+    }
+}
+
+impl<'a> Page<'a> {
+    /// Check the document for violations of the HTML spec
+    ///
+    /// See [`Node::validate`].
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut validator = Validator::default();
+        validator.element("html", &self.0.global, &self.0.children);
+        validator.diagnostics
+    }
+}
+
+#[derive(Default)]
+struct Validator<'n> {
+    path: Vec<usize>,
+    ancestors: Vec<&'static str>,
+    ids: HashSet<&'n str>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+const AUTOCAPITALIZE: &[&str] = &["off", "none", "on", "sentences", "words", "characters"];
+const BOOLEAN: &[&str] = &["true", "false"];
+const CONTENTEDITABLE: &[&str] = &["true", "false", "plaintext-only"];
+const DIR: &[&str] = &["ltr", "rtl", "auto"];
+const ENCTYPE: &[&str] = &[
+    "application/x-www-form-urlencoded",
+    "multipart/form-data",
+    "text/plain",
+];
+const ENTERKEYHINT: &[&str] = &["enter", "done", "go", "next", "previous", "search", "send"];
+const INPUTMODE: &[&str] = &[
+    "none", "text", "decimal", "numeric", "tel", "search", "email", "url",
+];
+const POPOVER: &[&str] = &["auto", "manual", "hint"];
+const TRANSLATE: &[&str] = &["yes", "no"];
+
+/// Check if an element can only contain phrasing content
+fn phrasing_only(tag: &str) -> bool {
+    matches!(
+        tag,
+        "abbr"
+            | "b"
+            | "bdi"
+            | "bdo"
+            | "button"
+            | "cite"
+            | "code"
+            | "data"
+            | "dfn"
+            | "em"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "i"
+            | "kbd"
+            | "label"
+            | "legend"
+            | "mark"
+            | "output"
+            | "p"
+            | "pre"
+            | "q"
+            | "s"
+            | "samp"
+            | "small"
+            | "span"
+            | "strong"
+            | "sub"
+            | "summary"
+            | "sup"
+            | "time"
+            | "u"
+            | "var"
+    )
+}
+
+/// Check if an element is interactive, so it cannot be inside of an `a` or `button`
+fn interactive(tag: &str) -> bool {
+    matches!(
+        tag,
+        "a" | "button" | "details" | "embed" | "iframe" | "input" | "label" | "select" | "textarea"
+    )
+}
+
+impl<'n> Validator<'n> {
+    fn report(&mut self, kind: DiagnosticKind) {
+        self.diagnostics.push(Diagnostic {
+            path: self.path.clone(),
+            kind,
+        });
+    }
+    fn keyword<K>(&mut self, attribute: &'static str, value: &str, keywords: &[K])
+    where
+        K: AttributeValue<'static> + Copy,
+    {
+        let is_keyword = |k: &K| k.into_attribute_value().eq_ignore_ascii_case(value);
+        if !value.is_empty() && !keywords.iter().any(is_keyword) {
+            self.report(DiagnosticKind::InvalidAttributeValue {
+                attribute,
+                value: value.into(),
+            });
+        }
+    }
+    fn node(&mut self, node: &'n Node<'_>) {
+        match node {
+            Node::A(a) => self.keyword("referrerpolicy", &a.referrerpolicy, ReferrerPolicy::ALL),
+            Node::Area(area) => {
+                self.keyword("referrerpolicy", &area.referrerpolicy, ReferrerPolicy::ALL)
+            }
+            Node::Audio(audio) => self.keyword("preload", &audio.preload, Preload::ALL),
+            Node::Button(button) => self.keyword("type", &button.r#type, ButtonType::ALL),
+            Node::Form(form) => {
+                self.keyword("method", &form.method, Method::ALL);
+                self.keyword("enctype", &form.enctype, ENCTYPE);
+            }
+            Node::Iframe(iframe) => {
+                self.keyword("loading", &iframe.loading, Loading::ALL);
+                self.keyword(
+                    "referrerpolicy",
+                    &iframe.referrerpolicy,
+                    ReferrerPolicy::ALL,
+                );
+            }
+            Node::Img(img) => {
+                if img.alt.is_empty() {
+                    self.report(DiagnosticKind::MissingAttribute {
+                        element: "img",
+                        attribute: "alt",
+                    });
+                }
+                self.keyword("crossorigin", &img.crossorigin, CrossOrigin::ALL);
+                self.keyword("decoding", &img.decoding, Decoding::ALL);
+                self.keyword("loading", &img.loading, Loading::ALL);
+                self.keyword("referrerpolicy", &img.referrerpolicy, ReferrerPolicy::ALL);
+            }
+            Node::Input(input) => {
+                self.keyword("type", &input.r#type, InputType::ALL);
+                if input.r#type.eq_ignore_ascii_case("image") && input.alt.is_empty() {
+                    self.report(DiagnosticKind::MissingAttribute {
+                        element: "input",
+                        attribute: "alt",
+                    });
+                }
+            }
+            Node::Link(link) => {
+                if link.href.is_empty() {
+                    self.report(DiagnosticKind::MissingAttribute {
+                        element: "link",
+                        attribute: "href",
+                    });
+                }
+                self.keyword("crossorigin", &link.crossorigin, CrossOrigin::ALL);
+                self.keyword("referrerpolicy", &link.referrerpolicy, ReferrerPolicy::ALL);
+            }
+            Node::Script(script) => {
+                if script.src.is_empty() && script.children.is_empty() {
+                    self.report(DiagnosticKind::MissingAttribute {
+                        element: "script",
+                        attribute: "src",
+                    });
+                }
+                self.keyword("crossorigin", &script.crossorigin, CrossOrigin::ALL);
+                self.keyword(
+                    "referrerpolicy",
+                    &script.referrerpolicy,
+                    ReferrerPolicy::ALL,
+                );
+            }
+            Node::Textarea(textarea) => self.keyword("wrap", &textarea.wrap, Wrap::ALL),
+            Node::Th(th) => self.keyword("scope", &th.scope, Scope::ALL),
+            Node::Track(track) => {
+                self.keyword("kind", &track.kind, TrackKind::ALL);
+                if track.src.is_empty() {
+                    self.report(DiagnosticKind::MissingAttribute {
+                        element: "track",
+                        attribute: "src",
+                    });
+                }
+            }
+            Node::Video(video) => {
+                self.keyword("crossorigin", &video.crossorigin, CrossOrigin::ALL);
+                self.keyword("preload", &video.preload, Preload::ALL);
+            }
+            _ => {}
+        }
+        let Some(global) = node.global() else {
+            return;
+        };
+        match node.tag() {
+            Some(tag) => self.element(tag, global, node.children().unwrap_or(&[])),
+            None => {
+                self.global(global);
+                self.children(node.children().unwrap_or(&[]));
+            }
+        }
+    }
+    fn element(
+        &mut self,
+        tag: &'static str,
+        global: &'n GlobalAttributes<'_>,
+        children: &'n [Node<'_>],
+    ) {
+        if let Some(&parent) = self.ancestors.last() {
+            if phrasing_only(parent) && closes_p(tag) {
+                self.report(DiagnosticKind::InvalidNesting {
+                    ancestor: parent,
+                    element: tag,
+                });
+            }
+        }
+        let ancestor = if interactive(tag) {
+            (self.ancestors.iter()).rfind(|&&t| t == "a" || t == "button")
+        } else if tag == "form" || tag == "label" {
+            self.ancestors.iter().rfind(|&&t| t == tag)
+        } else {
+            None
+        };
+        if let Some(&ancestor) = ancestor {
+            self.report(DiagnosticKind::InvalidNesting {
+                ancestor,
+                element: tag,
+            });
+        }
+        self.global(global);
+        self.ancestors.push(tag);
+        self.children(children);
+        self.ancestors.pop();
+    }
+    fn global(&mut self, global: &'n GlobalAttributes<'_>) {
+        if !global.id.is_empty() && !self.ids.insert(&global.id) {
+            self.report(DiagnosticKind::DuplicateId(global.id.to_string()));
+        }
+        self.keyword("autocapitalize", &global.autocapitalize, AUTOCAPITALIZE);
+        self.keyword("contenteditable", &global.contenteditable, CONTENTEDITABLE);
+        self.keyword("dir", &global.dir, DIR);
+        self.keyword("draggable", &global.draggable, BOOLEAN);
+        self.keyword("enterkeyhint", &global.enterkeyhint, ENTERKEYHINT);
+        self.keyword("inputmode", &global.inputmode, INPUTMODE);
+        self.keyword("popover", &global.popover, POPOVER);
+        self.keyword("spellcheck", &global.spellcheck, BOOLEAN);
+        self.keyword("translate", &global.translate, TRANSLATE);
+    }
+    fn children(&mut self, children: &'n [Node<'_>]) {
+        for (i, child) in children.iter().enumerate() {
+            self.path.push(i);
+            self.node(child);
+            self.path.pop();
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::borrow::Cow;
+
+/// A value that can be passed to an attribute
+///
+/// This is implemented for strings, numbers, [`Url`](crate::Url)s, and the typed values
+/// of attributes with enumerated values, like [`keywords::Method`](crate::keywords::Method)
+/// and [`aria::Live`](crate::aria::Live).
+pub trait AttributeValue<'a> {
+    /// Convert this into the string value of the attribute
+    fn into_attribute_value(self) -> Cow<'a, str>;
+}
+
+impl<'a> AttributeValue<'a> for &'a str {
+    fn into_attribute_value(self) -> Cow<'a, str> {
+        self.into()
+    }
+}
+
+impl<'a> AttributeValue<'a> for String {
+    fn into_attribute_value(self) -> Cow<'a, str> {
+        self.into()
+    }
+}
+
+impl<'a> AttributeValue<'a> for &'a String {
+    fn into_attribute_value(self) -> Cow<'a, str> {
+        self.into()
+    }
+}
+
+impl<'a> AttributeValue<'a> for Cow<'a, str> {
+    fn into_attribute_value(self) -> Cow<'a, str> {
+        self
+    }
+}
+
+/// `true` and `false` become `"true"` and `"false"`
+impl<'a> AttributeValue<'a> for bool {
+    fn into_attribute_value(self) -> Cow<'a, str> {
+        Cow::Borrowed(if self { "true" } else { "false" })
+    }
+}
+
+/// Marks a type as a value that the attribute `A` accepts on the element `E`
+///
+/// Strings are accepted by every attribute.
+/// Other values are only accepted by the attributes they make sense for:
+/// - [`bool`] by attributes whose values are `true` or `false`, like [`AriaExpanded`](crate::AriaExpanded)
+/// - Numbers by numeric attributes:
+///   - Any number by attributes like [`Width`](crate::Width), [`Min`](crate::Min), and [`Step`](crate::Step)
+///   - Any integer by [`Tabindex`](crate::Tabindex) and [`Start`](crate::Start)
+///   - Only [`usize`] by counts, like [`Colspan`](crate::Colspan) and [`Maxlength`](crate::Maxlength),
+///     so that integer literals are inferred as `usize`
+/// - [`Url`](crate::Url)s by [`Href`](crate::Href), [`Src`](crate::Src), and [`Action`](crate::Action)
+/// - The types in the [`keywords`](crate::keywords) and [`aria`](crate::aria) modules by their attributes.
+///   Values of [`Type`](crate::Type) are only accepted on their own element,
+///   like [`keywords::InputType`](crate::keywords::InputType) on `input`.
+///
+/// ```
+/// use hotman::*;
+///
+/// let menu = a((AriaExpanded(false), Tabindex(0), Href("/menu"), "Menu"));
+/// ```
+///
+/// ```compile_fail
+/// use hotman::*;
+///
+/// let link = a(Href(true));
+/// ```
+///
+/// ```compile_fail
+/// use hotman::*;
+///
+/// let link = a(Href(aria::Live::Polite));
+/// ```
+///
+/// ```compile_fail
+/// use hotman::*;
+///
+/// let cell = td(Colspan(-1));
+/// ```
+///
+/// ```compile_fail
+/// use hotman::*;
+///
+/// let cell = td(Colspan(1.5));
+/// ```
+///
+/// ```compile_fail
+/// use hotman::*;
+///
+/// let send = button(Type(keywords::InputType::Email));
+/// ```
+pub trait ValueOf<A, E> {}
+
+impl<A, E> ValueOf<A, E> for &str {}
+impl<A, E> ValueOf<A, E> for String {}
+impl<A, E> ValueOf<A, E> for &String {}
+impl<'a, A, E> ValueOf<A, E> for Cow<'a, str> {}
+
+/// Implement [`ValueOf`] for each of the types for each of the attributes
+macro_rules! value_of {
+    ($types:tt => $($attr:ident),* $(,)?) => {
+        $(value_of!(@attr $types $attr);)*
+    };
+    (@attr [$($ty:ty),*] $attr:ident) => {
+        $(impl<E> $crate::ValueOf<$crate::$attr, E> for $ty {})*
+    };
+}
+
+value_of!(
+    [bool] =>
+    AriaAtomic,
+    AriaBusy,
+    AriaChecked,
+    AriaCurrent,
+    AriaDisabled,
+    AriaExpanded,
+    AriaHaspopup,
+    AriaHidden,
+    AriaInvalid,
+    AriaModal,
+    AriaMultiline,
+    AriaMultiselectable,
+    AriaPressed,
+    AriaReadonly,
+    AriaRequired,
+    AriaSelected,
+    Contenteditable,
+    Draggable,
+    Spellcheck,
+);
+
+/// Implement [`AttributeValue`] for number types
+macro_rules! number_values {
+    (float: $($ty:ty),* $(,)?) => {
+        $(
+            /// # Panics
+            ///
+            /// Panics if the number is `NaN` or infinite, which are not valid attribute values.
+            impl<'a> AttributeValue<'a> for $ty {
+                fn into_attribute_value(self) -> Cow<'a, str> {
+                    assert!(self.is_finite(), "{self} is not a valid attribute value");
+                    self.to_string().into()
+                }
+            }
+        )*
+    };
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<'a> AttributeValue<'a> for $ty {
+                fn into_attribute_value(self) -> Cow<'a, str> {
+                    self.to_string().into()
+                }
+            }
+        )*
+    };
+}
+
+number_values!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+number_values!(float: f32, f64);
+
+value_of!(
+    [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64] =>
+    AriaValuemax,
+    AriaValuemin,
+    AriaValuenow,
+    Height,
+    Max,
+    Min,
+    Step,
+    Width,
+);
+
+value_of!(
+    [u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize] =>
+    Start,
+    Tabindex,
+);
+
+value_of!(
+    [usize] =>
+    AriaColcount,
+    AriaColindex,
+    AriaColspan,
+    AriaLevel,
+    AriaPosinset,
+    AriaRowcount,
+    AriaRowindex,
+    AriaRowspan,
+    AriaSetsize,
+    Cols,
+    Colspan,
+    Maxlength,
+    Minlength,
+    Rows,
+    Rowspan,
+    Size,
+    Span,
+);
+
+/// Define an enum of the allowed values of the given attributes
+///
+/// With `on Element`, the values are only accepted by the attributes of that element.
+macro_rules! value_enum {
+    (@value_of $name:ident [$($attribute:ident),+]) => {
+        $(impl<E> $crate::ValueOf<$crate::$attribute, E> for $name {})+
+    };
+    (@value_of $name:ident [$($attribute:ident),+] $element:ident) => {
+        $(
+            impl<'a> $crate::ValueOf<$crate::$attribute, $crate::element_structs::$element<'a>>
+                for $name
+            {
+            }
+        )+
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident for $($attribute:ident),+ $(on $element:ident)? { $($variant:ident = $value:literal),* $(,)? }
+    ) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                $variant,
+            )*
+        }
+
+        impl $name {
+            /// All of the values
+            pub const ALL: &'static [Self] = &[$(Self::$variant),*];
+            /// Get the string value
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl<'a> From<$name> for std::borrow::Cow<'a, str> {
+            fn from(value: $name) -> Self {
+                std::borrow::Cow::Borrowed(value.as_str())
+            }
+        }
+
+        impl<'a> $crate::AttributeValue<'a> for $name {
+            fn into_attribute_value(self) -> std::borrow::Cow<'a, str> {
+                self.into()
+            }
+        }
+
+        value_enum!(@value_of $name [$($attribute),+] $($element)?);
+    };
+}
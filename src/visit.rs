@@ -0,0 +1,94 @@
+use crate::Node;
+
+/// Visits the nodes of a tree with [`Node::walk`]
+///
+/// It is implemented for closures that take a `&Node`.
+pub trait Visitor<'a> {
+    /// Visit a node before its children
+    fn enter(&mut self, node: &Node<'a>);
+    /// Visit a node after its children
+    fn leave(&mut self, node: &Node<'a>) {
+        let _ = node;
+    }
+}
+
+impl<'a, F> Visitor<'a> for F
+where
+    F: FnMut(&Node<'a>),
+{
+    fn enter(&mut self, node: &Node<'a>) {
+        self(node)
+    }
+}
+
+/// Visits and modifies the nodes of a tree with [`Node::walk_mut`]
+///
+/// It is implemented for closures that take a `&mut Node`.
+pub trait VisitorMut<'a> {
+    /// Visit a node before its children
+    ///
+    /// Children that are added to the node will be visited.
+    fn enter(&mut self, node: &mut Node<'a>);
+    /// Visit a node after its children
+    fn leave(&mut self, node: &mut Node<'a>) {
+        let _ = node;
+    }
+}
+
+impl<'a, F> VisitorMut<'a> for F
+where
+    F: FnMut(&mut Node<'a>),
+{
+    fn enter(&mut self, node: &mut Node<'a>) {
+        self(node)
+    }
+}
+
+impl<'a> Node<'a> {
+    /// Visit this node and its descendants, depth-first
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let node = Node::from(div((h1("Title"), p(("Some ", em("text"))))));
+    /// let mut tags = Vec::new();
+    /// node.walk(&mut |node: &Node| tags.extend(node.tag_name().map(String::from)));
+    /// assert_eq!(tags, ["div", "h1", "p", "em"]);
+    /// ```
+    pub fn walk(&self, visitor: &mut impl Visitor<'a>) {
+        visitor.enter(self);
+        for child in self.children().unwrap_or(&[]) {
+            child.walk(visitor);
+        }
+        visitor.leave(self);
+    }
+    /// Visit and modify this node and its descendants, depth-first
+    ///
+    /// ```
+    /// use hotman::*;
+    ///
+    /// let mut node = Node::from(p((
+    ///     a((Href("/about"), "About")),
+    ///     a((Href("https://example.com"), "Elsewhere")),
+    /// )));
+    /// node.walk_mut(&mut |node: &mut Node| {
+    ///     if let Node::A(a) = node {
+    ///         if a.href.starts_with("https://") {
+    ///             a.rel = "noopener".into();
+    ///         }
+    ///     }
+    /// });
+    /// assert_eq!(node.to_string(), "\
+    /// <p>
+    ///     <a href=\"/about\">About</a>
+    ///     <a href=\"https://example.com\" rel=\"noopener\">Elsewhere</a>
+    /// </p>");
+    /// ```
+    pub fn walk_mut(&mut self, visitor: &mut impl VisitorMut<'a>) {
+        visitor.enter(self);
+        for child in self.children_mut().into_iter().flatten() {
+            child.walk_mut(visitor);
+        }
+        visitor.leave(self);
+    }
+}
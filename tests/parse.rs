@@ -0,0 +1,154 @@
+use hotman::*;
+
+fn error(html: &str) -> (usize, usize, ParseErrorKind) {
+    let error = Node::parse_fragment(html).unwrap_err();
+    (error.line, error.column, error.kind)
+}
+
+#[test]
+fn unterminated_comment() {
+    assert_eq!(
+        error("<p>a</p>\n  <!-- oops"),
+        (2, 3, ParseErrorKind::UnterminatedComment)
+    );
+}
+
+#[test]
+fn unterminated_tag() {
+    assert_eq!(
+        error("<div>\n<span class=\"a\""),
+        (2, 1, ParseErrorKind::UnterminatedTag)
+    );
+    assert_eq!(
+        error("<div title=\"unclosed>text</div>"),
+        (1, 1, ParseErrorKind::UnterminatedTag)
+    );
+    assert_eq!(
+        error("<!DOCTYPE html"),
+        (1, 1, ParseErrorKind::UnterminatedTag)
+    );
+}
+
+#[test]
+fn invalid_attribute_name() {
+    assert_eq!(
+        error("<div\n    =\"x\">"),
+        (2, 5, ParseErrorKind::InvalidAttributeName("=".into()))
+    );
+    assert_eq!(
+        error("<p a\"b=1>"),
+        (1, 4, ParseErrorKind::InvalidAttributeName("a\"b".into()))
+    );
+}
+
+#[test]
+fn unexpected_end_tag() {
+    assert_eq!(
+        error("<div></span></div>"),
+        (1, 6, ParseErrorKind::UnexpectedEndTag("span".into()))
+    );
+}
+
+#[test]
+fn unclosed_element() {
+    assert_eq!(
+        error("<main>\n\t<div>\n</main>"),
+        (2, 2, ParseErrorKind::UnclosedElement("div".into()))
+    );
+    assert_eq!(
+        error("<section><p>text"),
+        (1, 1, ParseErrorKind::UnclosedElement("section".into()))
+    );
+}
+
+#[test]
+fn error_columns_count_characters() {
+    assert_eq!(
+        error("<p>héllo</p><b>"),
+        (1, 13, ParseErrorKind::UnclosedElement("b".into()))
+    );
+}
+
+#[test]
+fn error_display() {
+    let error = Node::parse_fragment("<ul>\n<li></ol>").unwrap_err();
+    assert_eq!(error.to_string(), "unexpected end tag `</ol>` at 2:5");
+}
+
+#[test]
+fn implied_end_tags() {
+    let nodes = Node::parse_fragment("<ul><li>a<li>b</ul><p>one<p>two").unwrap();
+    assert_eq!(
+        nodes
+            .iter()
+            .map(|node| node.render_compact().to_string())
+            .collect::<String>(),
+        "<ul><li>a</li><li>b</li></ul><p>one</p><p>two</p>"
+    );
+}
+
+#[test]
+fn stray_markup_is_text() {
+    let nodes = Node::parse_fragment("a < b && c > d").unwrap();
+    assert_eq!(nodes[0].to_string(), "a &lt; b &amp;&amp; c &gt; d");
+}
+
+#[test]
+fn duplicate_attributes_keep_the_first() {
+    let nodes = Node::parse_fragment("<div id=a id=b></div>").unwrap();
+    assert_eq!(nodes[0].to_string(), "<div id=\"a\"></div>");
+}
+
+#[test]
+fn data_attributes() {
+    let nodes = Node::parse_fragment("<div data-Foo=1 data-bar=2 data-=3></div>").unwrap();
+    assert_eq!(
+        nodes[0].to_string(),
+        "<div data-foo=\"1\" data-bar=\"2\" data-=\"3\"></div>"
+    );
+}
+
+#[test]
+fn page_keeps_comments_around_html() {
+    let page =
+        Page::parse("<!-- before --><!DOCTYPE html>\n<html><body></body></html>\n<!-- after -->")
+            .unwrap();
+    assert_eq!(
+        page.render_compact().to_string(),
+        "<!DOCTYPE html><html><!-- before --><body></body><!-- after --></html>"
+    );
+}
+
+#[test]
+fn page_wraps_loose_nodes() {
+    let page = Page::parse("<p>a</p><p>b</p>").unwrap();
+    assert_eq!(
+        page.render_compact().to_string(),
+        "<!DOCTYPE html><html><p>a</p><p>b</p></html>"
+    );
+}
+
+#[test]
+fn unquoted_self_closing_round_trip() {
+    let options = RenderOptions {
+        unquote_attributes: true,
+        ..RenderOptions::compact()
+    };
+    let icon = svg((svg::ViewBox("0 0 20 20"), svg::circle(svg::R("10"))));
+    let html = icon.render_with(&options).to_string();
+    let nodes = Node::parse_fragment(&html).unwrap();
+    assert_eq!(nodes[0].render_with(&options).to_string(), html);
+}
+
+#[test]
+fn comments_round_trip() {
+    let node = Node::from(div(Comment("a --> b --!> c")));
+    let html = node.to_string();
+    let nodes = Node::parse_fragment(&html).unwrap();
+    assert_eq!(nodes.len(), 1);
+    let Node::Div(div) = &nodes[0] else {
+        panic!("expected a div, got {:?}", nodes[0]);
+    };
+    assert_eq!(div.children.len(), 1);
+    assert!(matches!(div.children[0], Node::Comment(_)));
+}
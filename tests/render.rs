@@ -0,0 +1,302 @@
+use hotman::*;
+
+fn doc() -> Node<'static> {
+    div((
+        Id("main"),
+        ul((li("one"), li(("two ", em("three"))))),
+        p(("Some ", b("bold"), " text")),
+    ))
+    .into()
+}
+
+fn render(node: &Node, options: &RenderOptions) -> String {
+    node.render_with(options).to_string()
+}
+
+#[test]
+fn pretty() {
+    assert_eq!(
+        render(&doc(), &RenderOptions::pretty()),
+        "\
+<div id=\"main\">
+    <ul>
+        <li>one</li>
+        <li>two <em>three</em></li>
+    </ul>
+    <p>Some <b>bold</b> text</p>
+</div>"
+    );
+    assert_eq!(doc().to_string(), render(&doc(), &RenderOptions::pretty()));
+}
+
+#[test]
+fn compact() {
+    assert_eq!(
+        render(&doc(), &RenderOptions::compact()),
+        "<div id=\"main\"><ul><li>one</li><li>two <em>three</em></li></ul><p>Some <b>bold</b> text</p></div>"
+    );
+}
+
+#[test]
+fn compact_ignores_layout_options() {
+    let options = RenderOptions {
+        indent: "\t".into(),
+        newline: Newline::CrLf,
+        max_width: Some(5),
+        wrap_attributes: true,
+        ..RenderOptions::compact()
+    };
+    assert_eq!(
+        render(&doc(), &options),
+        render(&doc(), &RenderOptions::compact())
+    );
+}
+
+#[test]
+fn indent() {
+    let options = RenderOptions {
+        indent: "\t".into(),
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(&ul((li("one"), li("two"))).into(), &options),
+        "<ul>\n\t<li>one</li>\n\t<li>two</li>\n</ul>"
+    );
+}
+
+#[test]
+fn newline() {
+    let options = RenderOptions {
+        newline: Newline::CrLf,
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(&ul((li("one"), li("two"))).into(), &options),
+        "<ul>\r\n    <li>one</li>\r\n    <li>two</li>\r\n</ul>"
+    );
+}
+
+#[test]
+fn max_width_wraps_text() {
+    let options = RenderOptions {
+        max_width: Some(20),
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(
+            &div((
+                h1("Foxes"),
+                p("the quick brown fox jumps over the lazy dog")
+            ))
+            .into(),
+            &options
+        ),
+        "\
+<div>
+    <h1>Foxes</h1>
+    <p>the quick
+    brown fox jumps
+    over the lazy
+    dog</p>
+</div>"
+    );
+}
+
+#[test]
+fn wrap_attributes() {
+    let link = Node::from(a((Href("/a/long/path"), Title("A long title"), "x")));
+    let options = RenderOptions {
+        max_width: Some(30),
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(&link, &options),
+        "\
+<a
+    title=\"A long title\"
+    href=\"/a/long/path\">x</a>"
+    );
+    let options = RenderOptions {
+        wrap_attributes: false,
+        ..options
+    };
+    assert_eq!(
+        render(&link, &options),
+        "<a title=\"A long title\" href=\"/a/long/path\">x</a>"
+    );
+}
+
+#[test]
+fn inline_elements() {
+    let node = Node::from(div((span("a"), a((Href("/"), "b")))));
+    assert_eq!(
+        render(&node, &RenderOptions::pretty()),
+        "\
+<div>
+    <span>a</span>
+    <a href=\"/\">b</a>
+</div>"
+    );
+    let options = RenderOptions {
+        inline_elements: true,
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(&node, &options),
+        "<div><span>a</span><a href=\"/\">b</a></div>"
+    );
+}
+
+#[test]
+fn omit_optional_end_tags() {
+    let options = RenderOptions {
+        omit_optional_end_tags: true,
+        ..RenderOptions::compact()
+    };
+    assert_eq!(
+        render(&doc(), &options),
+        "<div id=\"main\"><ul><li>one<li>two <em>three</em></ul><p>Some <b>bold</b> text</div>"
+    );
+    assert_eq!(
+        render(&a((Href("/"), p("x"))).into(), &options),
+        "<a href=\"/\"><p>x</p></a>"
+    );
+}
+
+#[test]
+fn unquote_attributes() {
+    let options = RenderOptions {
+        unquote_attributes: true,
+        ..RenderOptions::compact()
+    };
+    assert_eq!(
+        render(
+            &input((Type("text"), Value("two words"), Name("a=b"))).into(),
+            &options
+        ),
+        "<input name=\"a=b\" type=text value=\"two words\">"
+    );
+}
+
+#[test]
+fn unquote_attributes_on_self_closing_tags() {
+    let options = RenderOptions {
+        unquote_attributes: true,
+        ..RenderOptions::compact()
+    };
+    assert_eq!(
+        render(&svg(svg::circle(svg::R("10"))).into(), &options),
+        "<svg><circle r=10 /></svg>"
+    );
+    assert_eq!(
+        render(
+            &svg(svg::circle(svg::R("10"))).into(),
+            &RenderOptions::compact()
+        ),
+        "<svg><circle r=\"10\"/></svg>"
+    );
+}
+
+#[test]
+fn omitted_end_tags_and_unquoted_attributes() {
+    let options = RenderOptions {
+        omit_optional_end_tags: true,
+        unquote_attributes: true,
+        ..RenderOptions::compact()
+    };
+    assert_eq!(
+        render(
+            &ul((Class("items"), li((Class("a b"), "one")), li("two"))).into(),
+            &options
+        ),
+        "<ul class=items><li class=\"a b\">one<li>two</ul>"
+    );
+}
+
+#[test]
+fn pretty_with_omitted_end_tags() {
+    let options = RenderOptions {
+        omit_optional_end_tags: true,
+        indent: "  ".into(),
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(&ul((li("one"), li("two"))).into(), &options),
+        "<ul>\n  <li>one\n  <li>two\n</ul>"
+    );
+}
+
+#[test]
+fn wrapped_attributes_with_tabs_and_crlf() {
+    let options = RenderOptions {
+        indent: "\t".into(),
+        newline: Newline::CrLf,
+        max_width: Some(20),
+        unquote_attributes: true,
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(&div((h1("Logo"), img((Src("/logo.png"), Alt("The logo"))))).into(), &options),
+        "<div>\r\n\t<h1>Logo</h1>\r\n\t<img\r\n\t\talt=\"The logo\"\r\n\t\tsrc=/logo.png>\r\n</div>"
+    );
+}
+
+#[test]
+fn preformatted_content_is_kept() {
+    let options = RenderOptions {
+        max_width: Some(10),
+        ..RenderOptions::pretty()
+    };
+    assert_eq!(
+        render(
+            &div((h1("Code"), pre("\nline one is long\n  two"))).into(),
+            &options
+        ),
+        "<div>\n    <h1>Code</h1>\n    <pre>\n\nline one is long\n  two</pre>\n</div>"
+    );
+}
+
+#[test]
+fn text_is_escaped() {
+    assert_eq!(p("<script>&").to_string(), "<p>&lt;script&gt;&amp;</p>");
+    assert_eq!(
+        div(Title("\"'<>&")).to_string(),
+        "<div title=\"&quot;&#39;&lt;&gt;&amp;\"></div>"
+    );
+}
+
+#[test]
+fn raw_text_cannot_close_its_element() {
+    assert_eq!(
+        script("let s = '</script><script>alert(1)';").to_string(),
+        "<script>let s = '<\\/script><script>alert(1)';</script>"
+    );
+}
+
+#[test]
+fn comments_cannot_end_early() {
+    for (text, rendered) in [
+        ("--><script>", "<!---- ><script>-->"),
+        ("a --!> b", "<!--a --! > b-->"),
+        (">", "<!-- >-->"),
+        ("->", "<!-- ->-->"),
+        ("a <!-", "<!--a <!- -->"),
+        ("fine - text", "<!--fine - text-->"),
+    ] {
+        assert_eq!(Node::from(Comment(text)).to_string(), rendered, "{text:?}");
+    }
+}
+
+#[test]
+fn comments_in_foreign_content_cannot_end_early() {
+    assert_eq!(
+        svg(Comment("--><script>")).render_compact().to_string(),
+        "<svg><!---- ><script>--></svg>"
+    );
+}
+
+#[test]
+#[should_panic]
+fn uppercase_data_name() {
+    let _ = div(Data("fooBar", "1"));
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "strict")]
+
+use hotman::*;
+
+#[test]
+fn area_in_map() {
+    let links = map((
+        Name("m"),
+        area((Shape("rect"), Coords("0,0,10,10"), Href("/"), Alt("Home"))),
+    ));
+    assert_eq!(
+        links.render_compact().to_string(),
+        "<map name=\"m\"><area alt=\"Home\" coords=\"0,0,10,10\" href=\"/\" shape=\"rect\"></map>"
+    );
+}
+
+#[test]
+fn area_in_phrasing_content() {
+    let text = p(("See ", area((Href("/"), Alt("Home")))));
+    assert_eq!(
+        text.render_compact().to_string(),
+        "<p>See <area alt=\"Home\" href=\"/\"></p>"
+    );
+}